@@ -0,0 +1,68 @@
+#![feature(portable_simd)]
+
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+use generic_array::{functional::FunctionalSequence, typenum::*, ArrayLength, GenericArray};
+use rand::Rng;
+use std::simd::Simd;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut rng = rand::rng();
+
+    macro_rules! all_simd_benches {
+        ($bench:ident, $group:literal) => {{
+            let mut g = c.benchmark_group($group);
+
+            all_simd_benches!(@lens $bench, g, U15, U64, U100, U1023, U1024, U4096, Prod<U1000, U5>, U10000);
+
+            g.finish();
+        }};
+        (@lens $bench:ident, $g:ident, $($len:ty),*) => {
+            $($bench::<$len>(&mut rng, &mut $g);)*
+        };
+    }
+
+    all_simd_benches!(bench_map, "map");
+    all_simd_benches!(bench_zip, "zip");
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
+
+fn random_array<N: ArrayLength>(rng: &mut impl Rng) -> Box<GenericArray<f32, N>> {
+    let mut array = Box::<GenericArray<f32, N>>::default();
+    array.iter_mut().for_each(|x| *x = rng.random());
+    array
+}
+
+fn bench_map<N: ArrayLength>(rng: &mut impl Rng, g: &mut BenchmarkGroup<'_, WallTime>)
+where
+    GenericArray<f32, N>: Copy,
+{
+    let fixture = random_array::<N>(rng);
+
+    g.bench_function(format!("scalar/N{:08}", N::USIZE), |b| {
+        b.iter(|| black_box(*fixture).map(|x| x * 2.0 + 1.0))
+    });
+
+    g.bench_function(format!("simd/N{:08}", N::USIZE), |b| {
+        b.iter(|| black_box(*fixture).simd_map::<8>(|v| v * Simd::splat(2.0) + Simd::splat(1.0)))
+    });
+}
+
+fn bench_zip<N: ArrayLength>(rng: &mut impl Rng, g: &mut BenchmarkGroup<'_, WallTime>)
+where
+    GenericArray<f32, N>: Copy,
+{
+    let lhs = random_array::<N>(rng);
+    let rhs = random_array::<N>(rng);
+
+    g.bench_function(format!("scalar/N{:08}", N::USIZE), |b| {
+        b.iter(|| black_box(*lhs).zip(&*rhs, |l, r| l * r + 1.0))
+    });
+
+    g.bench_function(format!("simd/N{:08}", N::USIZE), |b| {
+        b.iter(|| black_box(*lhs).simd_zip::<8>(&rhs, |l, r| l * r + Simd::splat(1.0)))
+    });
+}
@@ -20,7 +20,9 @@
 /// * As of `generic-array 1.0`, [`From`]/[`from_array`](crate::GenericArray::from_array) can be used directly for a wide range of regular arrays.
 /// * The `[T; N: ArrayLength]` and `[T; usize]` explicit forms are limited to `Copy` values. Use
 ///   [`GenericArray::generate(|| value.clone())`](crate::GenericSequence::generate) for non-`Copy` items.
-/// * The `[T; usize]` explicit and `[0, 1, 2, 3]` implicit forms are limited to lengths supported by [`Const<U>`](typenum::Const)
+/// * The `[T; usize]` explicit and `[0, 1, 2, 3]` implicit forms are limited to lengths supported by [`Const<U>`](typenum::Const).
+///   For other lengths, convert a regular array with [`try_from_array`](crate::GenericArray::try_from_array)
+///   or [`from_array_asserted`](crate::GenericArray::from_array_asserted).
 #[macro_export]
 macro_rules! arr {
     ($($x:expr),* $(,)*) => ( $crate::GenericArray::from_array([$($x),*]) );
@@ -0,0 +1,541 @@
+//! Fixed-size bit set with a `typenum` bit count.
+//!
+//! [`GenericBitSet<Bits>`](GenericBitSet) stores `Bits` bits in a
+//! `GenericArray<usize, ⌈Bits / usize::BITS⌉>`, requiring no allocations or extra dependencies.
+//!
+//! ```rust
+//! use generic_array::{bitset::GenericBitSet, typenum::U100};
+//!
+//! let mut set = GenericBitSet::<U100>::new();
+//! set.set(3);
+//! set.set(64);
+//! set.set(99);
+//!
+//! assert!(set.test(64));
+//! assert_eq!(set.count_ones(), 3);
+//! assert_eq!(set.first_set(), Some(3));
+//! assert_eq!(set.last_set(), Some(99));
+//! assert!(set.iter().eq([3, 64, 99]));
+//! ```
+
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+use core::ops::{Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use core::ops::{Not, Shr, Sub, SubAssign};
+use core::{fmt, slice};
+
+use typenum::operator_aliases::{Shright, Sum};
+
+use crate::{ArrayLength, GenericArray};
+
+/// Number of bits in a single `usize` word
+const WORD_BITS: usize = usize::BITS as usize;
+
+/// `typenum` equivalent of `WORD_BITS - 1`
+#[cfg(target_pointer_width = "16")]
+type WordMask = typenum::U15;
+#[cfg(target_pointer_width = "32")]
+type WordMask = typenum::U31;
+#[cfg(target_pointer_width = "64")]
+type WordMask = typenum::U63;
+
+/// `typenum` equivalent of `log2(WORD_BITS)`
+#[cfg(target_pointer_width = "16")]
+type WordShift = typenum::U4;
+#[cfg(target_pointer_width = "32")]
+type WordShift = typenum::U5;
+#[cfg(target_pointer_width = "64")]
+type WordShift = typenum::U6;
+
+/// `⌈N / WORD_BITS⌉`
+type WordsFor<N> = Shright<Sum<N, WordMask>, WordShift>;
+
+/// An [`ArrayLength`] usable as the number of bits in a [`GenericBitSet`].
+///
+/// This is implemented for every `typenum` length where the number of backing `usize` words,
+/// `⌈Self / usize::BITS⌉`, can be computed, which covers all concrete lengths. It exists only to
+/// hide the `typenum` arithmetic bounds in generic code:
+///
+/// ```rust
+/// use generic_array::bitset::{BitSetLength, GenericBitSet};
+///
+/// fn count<N: BitSetLength>(set: &GenericBitSet<N>) -> usize {
+///     set.count_ones()
+/// }
+/// ```
+pub trait BitSetLength: ArrayLength {
+    /// Number of `usize` words required to store `Self` bits.
+    type Words: ArrayLength;
+}
+
+impl<N> BitSetLength for N
+where
+    N: ArrayLength + Add<WordMask>,
+    Sum<N, WordMask>: Shr<WordShift>,
+    WordsFor<N>: ArrayLength,
+{
+    type Words = WordsFor<N>;
+}
+
+/// A fixed-size set of bits with a `typenum` bit count, backed by a `GenericArray` of `usize` words.
+///
+/// Bit `i` is stored in bit `i % usize::BITS` of word `i / usize::BITS`. Any bits in the last word
+/// beyond `Bits` are always zero.
+#[repr(transparent)]
+pub struct GenericBitSet<Bits: BitSetLength> {
+    words: GenericArray<usize, Bits::Words>,
+}
+
+#[inline(never)]
+#[cold]
+const fn bit_index_fail() -> ! {
+    panic!("bit index out of range for GenericBitSet");
+}
+
+impl<Bits: BitSetLength> GenericBitSet<Bits> {
+    /// Creates a new bit set with all bits cleared.
+    ///
+    /// This method is `const` since Rust 1.75.0, but non-`const` before.
+    #[rustversion::attr(since(1.75), const)]
+    #[inline(always)]
+    pub fn new() -> Self {
+        GenericBitSet {
+            // SAFETY: all-zero is a valid `usize` array
+            words: unsafe { MaybeUninit::zeroed().assume_init() },
+        }
+    }
+
+    /// Creates a bit set from its backing words.
+    ///
+    /// Any bits in the last word beyond `Bits` are cleared.
+    ///
+    /// This method is `const` since Rust 1.83.0, but non-`const` before.
+    #[rustversion::attr(since(1.83), const)]
+    #[inline]
+    pub fn from_words(words: GenericArray<usize, Bits::Words>) -> Self {
+        let mut set = GenericBitSet { words };
+        set.mask_tail();
+        set
+    }
+
+    /// Returns the backing words of this bit set.
+    #[inline(always)]
+    pub const fn as_words(&self) -> &GenericArray<usize, Bits::Words> {
+        &self.words
+    }
+
+    /// Consumes the bit set and returns its backing words.
+    #[inline(always)]
+    pub const fn into_words(self) -> GenericArray<usize, Bits::Words> {
+        // SAFETY: `GenericBitSet` is `repr(transparent)` over the words,
+        // and this avoids the limitations on destructuring in `const fn`
+        unsafe { crate::const_transmute(self) }
+    }
+
+    /// Returns the number of bits in the set, which is always `Bits`.
+    #[inline(always)]
+    pub const fn len() -> usize {
+        Bits::USIZE
+    }
+
+    /// Returns `true` if bit `index` is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= Bits`.
+    #[inline]
+    pub const fn test(&self, index: usize) -> bool {
+        if index >= Bits::USIZE {
+            bit_index_fail();
+        }
+
+        let word = self.words.as_slice()[index / WORD_BITS];
+        word & (1 << (index % WORD_BITS)) != 0
+    }
+
+    /// Sets bit `index`, returning whether it was previously set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= Bits`.
+    ///
+    /// This method is `const` since Rust 1.83.0, but non-`const` before.
+    #[rustversion::attr(since(1.83), const)]
+    #[inline]
+    pub fn set(&mut self, index: usize) -> bool {
+        let (word, mask) = self.word_mut(index);
+        let was_set = *word & mask != 0;
+        *word |= mask;
+        was_set
+    }
+
+    /// Clears bit `index`, returning whether it was previously set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= Bits`.
+    ///
+    /// This method is `const` since Rust 1.83.0, but non-`const` before.
+    #[rustversion::attr(since(1.83), const)]
+    #[inline]
+    pub fn clear(&mut self, index: usize) -> bool {
+        let (word, mask) = self.word_mut(index);
+        let was_set = *word & mask != 0;
+        *word &= !mask;
+        was_set
+    }
+
+    /// Flips bit `index`, returning whether it was previously set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= Bits`.
+    ///
+    /// This method is `const` since Rust 1.83.0, but non-`const` before.
+    #[rustversion::attr(since(1.83), const)]
+    #[inline]
+    pub fn toggle(&mut self, index: usize) -> bool {
+        let (word, mask) = self.word_mut(index);
+        let was_set = *word & mask != 0;
+        *word ^= mask;
+        was_set
+    }
+
+    /// Sets bit `index` to `value`, returning whether it was previously set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= Bits`.
+    ///
+    /// This method is `const` since Rust 1.83.0, but non-`const` before.
+    #[rustversion::attr(since(1.83), const)]
+    #[inline]
+    pub fn put(&mut self, index: usize, value: bool) -> bool {
+        match value {
+            true => self.set(index),
+            false => self.clear(index),
+        }
+    }
+
+    /// Sets all bits.
+    #[inline]
+    pub fn set_all(&mut self) {
+        self.words.iter_mut().for_each(|w| *w = !0);
+        self.mask_tail();
+    }
+
+    /// Clears all bits.
+    #[inline]
+    pub fn clear_all(&mut self) {
+        self.words.iter_mut().for_each(|w| *w = 0);
+    }
+
+    /// Returns the number of set bits.
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns the number of cleared bits.
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        Bits::USIZE - self.count_ones()
+    }
+
+    /// Returns `true` if no bits are set.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns `true` if all bits are set.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.count_ones() == Bits::USIZE
+    }
+
+    /// Returns the index of the lowest set bit, or `None` if no bits are set.
+    #[inline]
+    pub fn first_set(&self) -> Option<usize> {
+        self.words
+            .iter()
+            .enumerate()
+            .find(|(_, &w)| w != 0)
+            .map(|(i, w)| i * WORD_BITS + w.trailing_zeros() as usize)
+    }
+
+    /// Returns the index of the highest set bit, or `None` if no bits are set.
+    #[inline]
+    pub fn last_set(&self) -> Option<usize> {
+        self.words
+            .iter()
+            .enumerate()
+            .rfind(|(_, &w)| w != 0)
+            .map(|(i, w)| i * WORD_BITS + (WORD_BITS - 1 - w.leading_zeros() as usize))
+    }
+
+    /// Returns an iterator over the indices of all set bits, in ascending order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.words)
+    }
+
+    /// Returns `true` if every bit set in `self` is also set in `other`.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .all(|(a, b)| a & !b == 0)
+    }
+
+    /// Returns `true` if `self` and `other` have no set bits in common.
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .all(|(a, b)| a & b == 0)
+    }
+
+    #[rustversion::attr(since(1.83), const)]
+    #[inline(always)]
+    fn word_mut(&mut self, index: usize) -> (&mut usize, usize) {
+        if index >= Bits::USIZE {
+            bit_index_fail();
+        }
+
+        (
+            &mut self.words.as_mut_slice()[index / WORD_BITS],
+            1 << (index % WORD_BITS),
+        )
+    }
+
+    /// Clears any bits in the last word beyond `Bits`, restoring the type invariant.
+    #[rustversion::attr(since(1.83), const)]
+    #[inline(always)]
+    fn mask_tail(&mut self) {
+        let rem = Bits::USIZE % WORD_BITS;
+
+        if rem != 0 {
+            if let Some(last) = self.words.as_mut_slice().last_mut() {
+                *last &= (1 << rem) - 1;
+            }
+        }
+    }
+}
+
+impl<Bits: BitSetLength> Default for GenericBitSet<Bits> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Bits: BitSetLength> Clone for GenericBitSet<Bits> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        GenericBitSet {
+            words: self.words.clone(),
+        }
+    }
+}
+
+impl<Bits: BitSetLength> Copy for GenericBitSet<Bits> where GenericArray<usize, Bits::Words>: Copy {}
+
+impl<Bits: BitSetLength> PartialEq for GenericBitSet<Bits> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words
+    }
+}
+
+impl<Bits: BitSetLength> Eq for GenericBitSet<Bits> {}
+
+impl<Bits: BitSetLength> Hash for GenericBitSet<Bits> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.words.hash(state)
+    }
+}
+
+impl<Bits: BitSetLength> fmt::Debug for GenericBitSet<Bits> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<Bits: BitSetLength> Extend<usize> for GenericBitSet<Bits> {
+    /// Sets every bit index yielded by the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is `>= Bits`.
+    #[inline]
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|i| {
+            self.set(i);
+        });
+    }
+}
+
+impl<Bits: BitSetLength> FromIterator<usize> for GenericBitSet<Bits> {
+    /// Creates a bit set with every bit index yielded by the iterator set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is `>= Bits`.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, Bits: BitSetLength> IntoIterator for &'a GenericBitSet<Bits> {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+macro_rules! impl_set_ops {
+    ($($op:ident::$op_fn:ident, $op_assign:ident::$op_assign_fn:ident => |$a:ident, $b:ident| $e:expr;)*) => {$(
+        impl<Bits: BitSetLength> $op_assign<&GenericBitSet<Bits>> for GenericBitSet<Bits> {
+            #[inline]
+            fn $op_assign_fn(&mut self, rhs: &GenericBitSet<Bits>) {
+                for ($a, &$b) in self.words.iter_mut().zip(rhs.words.iter()) {
+                    *$a = $e;
+                }
+            }
+        }
+
+        impl<Bits: BitSetLength> $op_assign for GenericBitSet<Bits> {
+            #[inline]
+            fn $op_assign_fn(&mut self, rhs: GenericBitSet<Bits>) {
+                $op_assign::$op_assign_fn(self, &rhs)
+            }
+        }
+
+        impl<Bits: BitSetLength> $op for GenericBitSet<Bits> {
+            type Output = GenericBitSet<Bits>;
+
+            #[inline]
+            fn $op_fn(mut self, rhs: GenericBitSet<Bits>) -> GenericBitSet<Bits> {
+                $op_assign::$op_assign_fn(&mut self, &rhs);
+                self
+            }
+        }
+
+        impl<Bits: BitSetLength> $op for &GenericBitSet<Bits> {
+            type Output = GenericBitSet<Bits>;
+
+            #[inline]
+            fn $op_fn(self, rhs: &GenericBitSet<Bits>) -> GenericBitSet<Bits> {
+                let mut out = self.clone();
+                $op_assign::$op_assign_fn(&mut out, rhs);
+                out
+            }
+        }
+    )*};
+}
+
+impl_set_ops! {
+    // union
+    BitOr::bitor, BitOrAssign::bitor_assign => |a, b| *a | b;
+    // intersection
+    BitAnd::bitand, BitAndAssign::bitand_assign => |a, b| *a & b;
+    // symmetric difference
+    BitXor::bitxor, BitXorAssign::bitxor_assign => |a, b| *a ^ b;
+    // difference
+    Sub::sub, SubAssign::sub_assign => |a, b| *a & !b;
+}
+
+impl<Bits: BitSetLength> Not for GenericBitSet<Bits> {
+    type Output = GenericBitSet<Bits>;
+
+    /// Returns the complement of the set.
+    #[inline]
+    fn not(mut self) -> GenericBitSet<Bits> {
+        self.words.iter_mut().for_each(|w| *w = !*w);
+        self.mask_tail();
+        self
+    }
+}
+
+impl<Bits: BitSetLength> Not for &GenericBitSet<Bits> {
+    type Output = GenericBitSet<Bits>;
+
+    /// Returns the complement of the set.
+    #[inline]
+    fn not(self) -> GenericBitSet<Bits> {
+        !self.clone()
+    }
+}
+
+/// An iterator over the indices of set bits in a [`GenericBitSet`], in ascending order.
+#[derive(Clone)]
+pub struct Iter<'a> {
+    // Invariant: `word` holds the not-yet-yielded bits of the word starting at bit `base`,
+    // and `rest` holds the words after it.
+    word: usize,
+    base: usize,
+    rest: slice::Iter<'a, usize>,
+}
+
+impl<'a> Iter<'a> {
+    #[inline]
+    fn new(words: &'a [usize]) -> Self {
+        let mut rest = words.iter();
+
+        Iter {
+            word: rest.next().copied().unwrap_or(0),
+            base: 0,
+            rest,
+        }
+    }
+}
+
+impl fmt::Debug for Iter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.clone()).finish()
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            self.word = *self.rest.next()?;
+            self.base += WORD_BITS;
+        }
+
+        let bit = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1; // clear lowest set bit
+
+        Some(self.base + bit)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.word.count_ones() as usize
+            + self
+                .rest
+                .clone()
+                .map(|w| w.count_ones() as usize)
+                .sum::<usize>();
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
@@ -0,0 +1,284 @@
+//! Buffering of arbitrary-length input into fixed-size blocks.
+//!
+//! [`BlockBuffer<BlockSize>`](BlockBuffer) accumulates input for block-based algorithms such as
+//! hash functions, MACs and block cipher modes, handing out each complete
+//! `GenericArray<u8, BlockSize>` block as soon as it is filled and keeping the tail
+//! until more input arrives or the buffer is padded.
+//!
+//! ```rust
+//! use generic_array::{block_buffer::BlockBuffer, typenum::U4};
+//!
+//! let mut buffer = BlockBuffer::<U4>::new();
+//! let mut out = Vec::new();
+//!
+//! buffer.digest_blocks(b"hello", |block| out.extend_from_slice(block));
+//! buffer.digest_blocks(b" world", |block| out.extend_from_slice(block));
+//!
+//! assert_eq!(out, b"hello wo");
+//! assert_eq!(buffer.data(), b"rld");
+//! assert_eq!(buffer.remaining(), 1);
+//!
+//! // ISO/IEC 7816-4 padding: a single `0x80` byte, then zeroes
+//! assert_eq!(buffer.pad_with_iso7816().as_slice(), b"rld\x80");
+//! assert!(buffer.data().is_empty());
+//! ```
+
+use core::fmt;
+
+use crate::{ArrayLength, GenericArray};
+
+/// A buffer that splits arbitrary-length input into `GenericArray<u8, BlockSize>` blocks.
+///
+/// Blocks are processed eagerly, so the buffer never holds a complete block between calls;
+/// between zero and `BlockSize - 1` bytes are kept for the next call or for padding.
+pub struct BlockBuffer<BlockSize: ArrayLength> {
+    // Invariants: pos < BlockSize (or pos == 0 when BlockSize == 0), buffer[pos..] is all zeroes
+    buffer: GenericArray<u8, BlockSize>,
+    pos: usize,
+}
+
+impl<BlockSize: ArrayLength> BlockBuffer<BlockSize> {
+    /// Creates an empty buffer.
+    ///
+    /// This method is `const` since Rust 1.75.0, but non-`const` before.
+    #[rustversion::attr(since(1.75), const)]
+    #[inline(always)]
+    pub fn new() -> Self {
+        BlockBuffer {
+            // SAFETY: all-zero is a valid `u8` array
+            buffer: unsafe { core::mem::MaybeUninit::zeroed().assume_init() },
+            pos: 0,
+        }
+    }
+
+    /// Returns the size of a block in bytes, `BlockSize`.
+    #[inline(always)]
+    pub const fn block_size() -> usize {
+        BlockSize::USIZE
+    }
+
+    /// Returns the number of bytes currently held in the buffer.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes needed to complete the current block.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        BlockSize::USIZE - self.pos
+    }
+
+    /// Returns the bytes currently held in the buffer.
+    #[inline(always)]
+    pub fn data(&self) -> &[u8] {
+        &self.buffer[..self.pos]
+    }
+
+    /// Discards any buffered data.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.buffer[..self.pos].fill(0);
+        self.pos = 0;
+    }
+
+    /// Appends `input` to the buffer, calling `compress` on each block as it is completed.
+    ///
+    /// Whole blocks of `input` are passed to `compress` directly from the input slice
+    /// via [`GenericArray::chunks_from_slice`], without being copied into the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BlockSize` is `U0` _AND_ `input` is not empty.
+    pub fn digest_blocks<F>(&mut self, mut input: &[u8], mut compress: F)
+    where
+        F: FnMut(&GenericArray<u8, BlockSize>),
+    {
+        let rem = self.remaining();
+
+        if input.len() < rem {
+            self.buffer[self.pos..self.pos + input.len()].copy_from_slice(input);
+            self.pos += input.len();
+            return;
+        }
+
+        if self.pos != 0 {
+            let (head, tail) = input.split_at(rem);
+            self.buffer[self.pos..].copy_from_slice(head);
+            compress(&self.buffer);
+            input = tail;
+        }
+
+        let (blocks, tail) = GenericArray::<u8, BlockSize>::chunks_from_slice(input);
+
+        for block in blocks {
+            compress(block);
+        }
+
+        self.buffer[..tail.len()].copy_from_slice(tail);
+        self.buffer[tail.len()..].fill(0);
+        self.pos = tail.len();
+    }
+
+    /// Writes a `0x80` byte after the buffered data and `suffix` at the very end of the final
+    /// block, compressing an extra block first if both do not fit.
+    #[inline]
+    fn digest_pad<F>(&mut self, suffix: &[u8], mut compress: F)
+    where
+        F: FnMut(&GenericArray<u8, BlockSize>),
+    {
+        assert!(
+            suffix.len() < BlockSize::USIZE,
+            "BlockSize is too small for the padding"
+        );
+
+        self.buffer[self.pos] = 0x80;
+
+        if BlockSize::USIZE - self.pos - 1 < suffix.len() {
+            compress(&self.buffer);
+            self.buffer.fill(0);
+        }
+
+        let suffix_pos = BlockSize::USIZE - suffix.len();
+        self.buffer[suffix_pos..].copy_from_slice(suffix);
+        compress(&self.buffer);
+
+        self.buffer.fill(0);
+        self.pos = 0;
+    }
+
+    /// Applies Merkle–Damgård length padding with a 64-bit big-endian length, as used by
+    /// SHA-1 and SHA-256, and compresses the final one or two blocks.
+    ///
+    /// The buffered data is followed by a `0x80` byte, then zeroes, then `data_len`
+    /// (the total length of the message **in bits**) in the last 8 bytes of the final block.
+    ///
+    /// The buffer is empty afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BlockSize` is less than 9 bytes.
+    #[inline]
+    pub fn len64_padding_be<F>(&mut self, data_len: u64, compress: F)
+    where
+        F: FnMut(&GenericArray<u8, BlockSize>),
+    {
+        self.digest_pad(&data_len.to_be_bytes(), compress);
+    }
+
+    /// Applies Merkle–Damgård length padding with a 64-bit little-endian length, as used by
+    /// MD5, and compresses the final one or two blocks.
+    ///
+    /// The buffered data is followed by a `0x80` byte, then zeroes, then `data_len`
+    /// (the total length of the message **in bits**) in the last 8 bytes of the final block.
+    ///
+    /// The buffer is empty afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BlockSize` is less than 9 bytes.
+    #[inline]
+    pub fn len64_padding_le<F>(&mut self, data_len: u64, compress: F)
+    where
+        F: FnMut(&GenericArray<u8, BlockSize>),
+    {
+        self.digest_pad(&data_len.to_le_bytes(), compress);
+    }
+
+    /// Applies Merkle–Damgård length padding with a 128-bit big-endian length, as used by
+    /// SHA-512, and compresses the final one or two blocks.
+    ///
+    /// The buffered data is followed by a `0x80` byte, then zeroes, then `data_len`
+    /// (the total length of the message **in bits**) in the last 16 bytes of the final block.
+    ///
+    /// The buffer is empty afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BlockSize` is less than 17 bytes.
+    #[inline]
+    pub fn len128_padding_be<F>(&mut self, data_len: u128, compress: F)
+    where
+        F: FnMut(&GenericArray<u8, BlockSize>),
+    {
+        self.digest_pad(&data_len.to_be_bytes(), compress);
+    }
+
+    /// Returns the final block with the buffered data padded by zeroes.
+    ///
+    /// A full block of zeroes is returned if the buffer is empty.
+    ///
+    /// The buffer is empty afterwards.
+    #[inline]
+    pub fn pad_with_zeros(&mut self) -> GenericArray<u8, BlockSize> {
+        let block = self.buffer.clone();
+        self.reset();
+        block
+    }
+
+    /// Returns the final block with the buffered data padded according to PKCS#7,
+    /// where each padding byte is the number of padding bytes.
+    ///
+    /// A full block of padding is returned if the buffer is empty.
+    ///
+    /// The buffer is empty afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BlockSize` is `U0` or greater than 255 bytes.
+    #[inline]
+    pub fn pad_with_pkcs7(&mut self) -> GenericArray<u8, BlockSize> {
+        assert!(
+            BlockSize::USIZE > 0 && BlockSize::USIZE <= 255,
+            "PKCS#7 padding requires a BlockSize between 1 and 255 bytes"
+        );
+
+        let mut block = self.buffer.clone();
+        block[self.pos..].fill(self.remaining() as u8);
+        self.reset();
+        block
+    }
+
+    /// Returns the final block with the buffered data padded according to ISO/IEC 7816-4,
+    /// a single `0x80` byte followed by zeroes.
+    ///
+    /// The buffer is empty afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BlockSize` is `U0`.
+    #[inline]
+    pub fn pad_with_iso7816(&mut self) -> GenericArray<u8, BlockSize> {
+        let mut block = self.buffer.clone();
+        block[self.pos] = 0x80;
+        self.reset();
+        block
+    }
+}
+
+impl<BlockSize: ArrayLength> Default for BlockBuffer<BlockSize> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<BlockSize: ArrayLength> Clone for BlockBuffer<BlockSize> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        BlockBuffer {
+            buffer: self.buffer.clone(),
+            pos: self.pos,
+        }
+    }
+}
+
+impl<BlockSize: ArrayLength> fmt::Debug for BlockBuffer<BlockSize> {
+    // buffered data is left out, as it is often secret
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockBuffer")
+            .field("block_size", &BlockSize::USIZE)
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
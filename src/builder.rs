@@ -0,0 +1,160 @@
+//! Safe, incremental construction of a [`GenericArray`].
+//!
+//! [`GenericArrayBuilder<T, N>`](GenericArrayBuilder) is filled one element at a time
+//! and turned into a `GenericArray<T, N>` once all `N` elements are present. Elements pushed so far
+//! are dropped if the builder is dropped first, including on panic or early return.
+//!
+//! ```rust
+//! use generic_array::{arr, builder::GenericArrayBuilder, typenum::U3};
+//!
+//! let mut builder = GenericArrayBuilder::<i32, U3>::new();
+//!
+//! for x in [1, 2, 3, 4] {
+//!     // full, so the value is handed back
+//!     if let Err(x) = builder.push(x) {
+//!         assert_eq!(x, 4);
+//!     }
+//! }
+//!
+//! assert!(builder.is_full());
+//! assert_eq!(builder.try_finish().ok(), Some(arr![1, 2, 3]));
+//! ```
+
+use core::mem::MaybeUninit;
+use core::{fmt, mem, ptr, slice};
+
+use crate::{ArrayLength, GenericArray};
+
+/// A partially-initialized `GenericArray<T, N>`, filled from the front.
+///
+/// This is the safe counterpart of the builders in
+/// `internals`, for use in custom collection logic.
+pub struct GenericArrayBuilder<T, N: ArrayLength> {
+    // Invariant: len <= N, and only buf[..len] is initialized
+    buf: GenericArray<MaybeUninit<T>, N>,
+    len: usize,
+}
+
+impl<T, N: ArrayLength> GenericArrayBuilder<T, N> {
+    /// Creates an empty builder.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        GenericArrayBuilder {
+            buf: GenericArray::uninit(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the finished array, `N`.
+    #[inline(always)]
+    pub const fn capacity() -> usize {
+        N::USIZE
+    }
+
+    /// Returns the number of elements pushed so far.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no elements have been pushed.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if all `N` elements have been pushed.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len == N::USIZE
+    }
+
+    /// Appends an element.
+    ///
+    /// Returns the value back as `Err` if the builder is already full.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        self.buf[self.len].write(value);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the last element pushed, if any.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+
+        // SAFETY: the element was initialized, and is no longer tracked by `len`
+        Some(unsafe { self.buf[self.len].assume_init_read() })
+    }
+
+    /// Returns the elements pushed so far.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { slice::from_raw_parts(self.buf.as_ptr() as *const T, self.len) }
+    }
+
+    /// Returns the elements pushed so far, mutably.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` elements are initialized
+        unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    /// Drops all elements pushed so far, leaving the builder empty.
+    #[inline]
+    pub fn clear(&mut self) {
+        let elements: *mut [T] = self.as_mut_slice();
+
+        // reset first, so a panicking drop can't cause a double drop
+        self.len = 0;
+
+        unsafe { ptr::drop_in_place(elements) }
+    }
+
+    /// Returns the finished array if all `N` elements have been pushed,
+    /// otherwise returns the builder unchanged.
+    #[inline]
+    pub fn try_finish(self) -> Result<GenericArray<T, N>, Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+
+        // SAFETY: all elements are initialized, and `self` is forgotten so none are dropped
+        unsafe {
+            let buf = ptr::read(&self.buf);
+            mem::forget(self);
+            Ok(GenericArray::assume_init(buf))
+        }
+    }
+}
+
+impl<T, N: ArrayLength> Drop for GenericArrayBuilder<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T, N: ArrayLength> Default for GenericArrayBuilder<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, N: ArrayLength> fmt::Debug for GenericArrayBuilder<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
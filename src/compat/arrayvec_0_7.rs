@@ -0,0 +1,112 @@
+use arrayvec_0_7::ArrayVec;
+
+use super::fixed_vec::CapacityAsserter;
+use crate::{ArrayLength, ExtendFromGenericArray, GenericArray, LengthError};
+
+/// Succeeds only if the vector holds exactly `N` elements, such as a full `ArrayVec<T, N>`.
+impl<T, N: ArrayLength, const CAP: usize> TryFrom<ArrayVec<T, CAP>> for GenericArray<T, N> {
+    type Error = LengthError;
+
+    #[inline]
+    fn try_from(vec: ArrayVec<T, CAP>) -> Result<Self, Self::Error> {
+        if vec.len() != N::USIZE {
+            return Err(LengthError);
+        }
+
+        GenericArray::try_from_iter(vec)
+    }
+}
+
+/// The capacity `CAP` must be at least `N`, which is checked at compile time.
+impl<T, N: ArrayLength, const CAP: usize> From<GenericArray<T, N>> for ArrayVec<T, CAP> {
+    #[inline]
+    fn from(array: GenericArray<T, N>) -> Self {
+        let () = CapacityAsserter::<N, CAP>::ASSERT_CAPACITY;
+
+        let mut vec = ArrayVec::new();
+
+        for el in array {
+            // SAFETY: `N <= CAP`
+            unsafe { vec.push_unchecked(el) };
+        }
+
+        vec
+    }
+}
+
+impl<T, const CAP: usize> ExtendFromGenericArray<T> for ArrayVec<T, CAP> {
+    #[inline]
+    fn extend_from_generic_array<N: ArrayLength>(
+        &mut self,
+        array: GenericArray<T, N>,
+    ) -> Result<(), GenericArray<T, N>> {
+        if self.remaining_capacity() < N::USIZE {
+            return Err(array);
+        }
+
+        for el in array {
+            // SAFETY: there is room for all `N` elements
+            unsafe { self.push_unchecked(el) };
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrayvec_0_7::ArrayVec;
+
+    use crate::typenum::{U0, U4};
+    use crate::{arr, ExtendFromGenericArray, GenericArray, LengthError};
+
+    #[test]
+    fn try_from_vec() {
+        let vec = ArrayVec::from([1u8, 2, 3, 4]);
+        assert_eq!(GenericArray::try_from(vec).unwrap(), arr![1u8, 2, 3, 4]);
+
+        // a larger capacity is fine, only the length must match
+        let vec: ArrayVec<u8, 8> = [1, 2, 3].into_iter().collect();
+        assert_eq!(GenericArray::try_from(vec).unwrap(), arr![1u8, 2, 3]);
+
+        let vec: ArrayVec<u8, 4> = [1, 2, 3].into_iter().collect();
+        assert!(matches!(
+            GenericArray::<u8, U4>::try_from(vec),
+            Err(LengthError)
+        ));
+
+        let vec = ArrayVec::<u8, 4>::new();
+        assert!(GenericArray::<u8, U0>::try_from(vec).is_ok());
+    }
+
+    #[test]
+    fn from_array() {
+        let vec: ArrayVec<u8, 4> = arr![1, 2, 3, 4].into();
+        assert!(vec.is_full());
+        assert_eq!(vec[..], [1, 2, 3, 4]);
+
+        let vec: ArrayVec<u8, 6> = arr![1, 2, 3].into();
+        assert_eq!(vec[..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn extend() {
+        let mut vec = ArrayVec::<u8, 6>::new();
+        vec.push(0);
+
+        assert_eq!(vec.extend_from_generic_array(arr![1, 2, 3]), Ok(()));
+        assert_eq!(vec[..], [0, 1, 2, 3]);
+
+        assert_eq!(
+            vec.extend_from_generic_array(arr![4, 5, 6]),
+            Err(arr![4, 5, 6])
+        );
+        assert_eq!(vec[..], [0, 1, 2, 3]);
+
+        assert_eq!(vec.extend_from_generic_array(arr![4, 5]), Ok(()));
+        assert!(vec.is_full());
+
+        let empty = GenericArray::<u8, U0>::default();
+        assert_eq!(vec.extend_from_generic_array(empty), Ok(()));
+    }
+}
@@ -0,0 +1,129 @@
+use heapless_0_9::vec::{VecInner, VecStorage};
+use heapless_0_9::{LenType, Vec as HeaplessVec};
+
+use super::fixed_vec::CapacityAsserter;
+use crate::{ArrayLength, ExtendFromGenericArray, GenericArray, LengthError};
+
+/// Succeeds only if the vector holds exactly `N` elements, such as a full `Vec<T, N>`.
+impl<T, N: ArrayLength, LenT: LenType, const CAP: usize> TryFrom<HeaplessVec<T, CAP, LenT>>
+    for GenericArray<T, N>
+{
+    type Error = LengthError;
+
+    #[inline]
+    fn try_from(vec: HeaplessVec<T, CAP, LenT>) -> Result<Self, Self::Error> {
+        if vec.len() != N::USIZE {
+            return Err(LengthError);
+        }
+
+        GenericArray::try_from_iter(vec)
+    }
+}
+
+/// The capacity `CAP` must be at least `N`, which is checked at compile time,
+/// like `heapless::Vec::from_array`.
+impl<T, N: ArrayLength, LenT: LenType, const CAP: usize> From<GenericArray<T, N>>
+    for HeaplessVec<T, CAP, LenT>
+{
+    #[inline]
+    fn from(array: GenericArray<T, N>) -> Self {
+        let () = CapacityAsserter::<N, CAP>::ASSERT_CAPACITY;
+
+        let mut vec = HeaplessVec::new();
+
+        for el in array {
+            // SAFETY: `N <= CAP`
+            unsafe { vec.push_unchecked(el) };
+        }
+
+        vec
+    }
+}
+
+impl<T, LenT: LenType, S: VecStorage<T> + ?Sized> ExtendFromGenericArray<T>
+    for VecInner<T, LenT, S>
+{
+    #[inline]
+    fn extend_from_generic_array<N: ArrayLength>(
+        &mut self,
+        array: GenericArray<T, N>,
+    ) -> Result<(), GenericArray<T, N>> {
+        if self.capacity() - self.len() < N::USIZE {
+            return Err(array);
+        }
+
+        for el in array {
+            // SAFETY: there is room for all `N` elements
+            unsafe { self.push_unchecked(el) };
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heapless_0_9::Vec as HeaplessVec;
+
+    use crate::typenum::{U0, U3, U4};
+    use crate::{arr, ExtendFromGenericArray, GenericArray, LengthError};
+
+    #[test]
+    fn try_from_vec() {
+        let vec = HeaplessVec::<u8, 4>::from_array([1, 2, 3, 4]);
+        assert_eq!(GenericArray::try_from(vec).unwrap(), arr![1u8, 2, 3, 4]);
+
+        // a larger capacity is fine, only the length must match
+        let vec = HeaplessVec::<u8, 8, u8>::from_array([1, 2, 3]);
+        assert_eq!(GenericArray::try_from(vec).unwrap(), arr![1u8, 2, 3]);
+
+        let vec = HeaplessVec::<u8, 4>::from_array([1, 2, 3]);
+        assert!(matches!(
+            GenericArray::<u8, U4>::try_from(vec),
+            Err(LengthError)
+        ));
+
+        let vec = HeaplessVec::<u8, 4>::new();
+        assert!(GenericArray::<u8, U0>::try_from(vec).is_ok());
+    }
+
+    #[test]
+    fn from_array() {
+        let vec: HeaplessVec<u8, 4> = arr![1, 2, 3, 4].into();
+        assert!(vec.is_full());
+        assert_eq!(vec, [1, 2, 3, 4]);
+
+        let vec: HeaplessVec<u8, 6, u8> = arr![1, 2, 3].into();
+        assert_eq!(vec, [1, 2, 3]);
+    }
+
+    #[test]
+    fn extend() {
+        let mut vec = HeaplessVec::<u8, 6>::new();
+        vec.push(0).unwrap();
+
+        assert_eq!(vec.extend_from_generic_array(arr![1, 2, 3]), Ok(()));
+        assert_eq!(vec, [0, 1, 2, 3]);
+
+        assert_eq!(
+            vec.extend_from_generic_array(arr![4, 5, 6]),
+            Err(arr![4, 5, 6])
+        );
+        assert_eq!(vec, [0, 1, 2, 3]);
+
+        assert_eq!(vec.extend_from_generic_array(arr![4, 5]), Ok(()));
+        assert!(vec.is_full());
+
+        let empty = GenericArray::<u8, U0>::default();
+        assert_eq!(vec.extend_from_generic_array(empty), Ok(()));
+
+        // through a `VecView` as well
+        let mut vec = HeaplessVec::<u8, 3>::new();
+        let view = vec.as_mut_view();
+        assert_eq!(
+            view.extend_from_generic_array(GenericArray::<u8, U3>::default()),
+            Ok(())
+        );
+        assert_eq!(vec, [0, 0, 0]);
+    }
+}
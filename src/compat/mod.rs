@@ -3,3 +3,65 @@ mod generic_array_0_14;
 
 #[cfg(feature = "hybrid-array-0_4")]
 mod hybrid_array_0_4;
+
+#[cfg(feature = "heapless-0_9")]
+mod heapless_0_9;
+
+#[cfg(feature = "arrayvec-0_7")]
+mod arrayvec_0_7;
+
+#[cfg(feature = "tinyvec-1")]
+mod tinyvec_1;
+
+#[cfg(feature = "nalgebra-0_35")]
+mod nalgebra_0_35;
+
+#[cfg(feature = "ndarray-0_17")]
+mod ndarray_0_17;
+
+#[cfg(any(
+    feature = "heapless-0_9",
+    feature = "arrayvec-0_7",
+    feature = "tinyvec-1"
+))]
+pub use self::fixed_vec::ExtendFromGenericArray;
+
+#[cfg(any(
+    feature = "heapless-0_9",
+    feature = "arrayvec-0_7",
+    feature = "tinyvec-1"
+))]
+mod fixed_vec {
+    #[cfg(any(feature = "heapless-0_9", feature = "arrayvec-0_7"))]
+    use core::marker::PhantomData;
+
+    use crate::{ArrayLength, GenericArray};
+
+    /// Moving a whole `GenericArray` into a fixed-capacity vector, such as
+    /// `heapless::Vec`, `arrayvec::ArrayVec` or `tinyvec::ArrayVec`.
+    pub trait ExtendFromGenericArray<T> {
+        /// Moves all elements of `array` onto the end of the vector, in order.
+        ///
+        /// # Errors
+        ///
+        /// Returns `array` unchanged if the vector doesn't have room for all of its elements,
+        /// leaving the vector as it was.
+        fn extend_from_generic_array<N: ArrayLength>(
+            &mut self,
+            array: GenericArray<T, N>,
+        ) -> Result<(), GenericArray<T, N>>;
+    }
+
+    /// Compile-time check that a vector with capacity `CAP` can hold `N` elements
+    #[cfg(any(feature = "heapless-0_9", feature = "arrayvec-0_7"))]
+    pub(crate) struct CapacityAsserter<N, const CAP: usize>(PhantomData<N>);
+
+    #[cfg(any(feature = "heapless-0_9", feature = "arrayvec-0_7"))]
+    impl<N: ArrayLength, const CAP: usize> CapacityAsserter<N, CAP> {
+        pub(crate) const ASSERT_CAPACITY: () = {
+            if N::USIZE > CAP {
+                panic!("GenericArray is longer than the vector capacity");
+            }
+        };
+    }
+}
@@ -0,0 +1,206 @@
+//! Views and conversions between `GenericArray` and `nalgebra` 0.35 vectors and matrices.
+//!
+//! Lengths are mapped to `nalgebra` dimensions through its own typenum bridge, [`ToConst`],
+//! which covers `U0` to `U127`.
+//!
+//! A nested `GenericArray<GenericArray<T, C>, R>` is taken to be `R` rows of `C` columns,
+//! so matrix views of it are row-major, with a row stride of `C`.
+
+use nalgebra_0_35::{
+    ArrayStorage, Const, DimName, MatrixView, MatrixViewMut, SMatrix, SVector, Scalar, ToConst,
+    VectorView, VectorViewMut, U1,
+};
+
+use crate::{const_transmute, ArrayLength, GenericArray};
+
+/// Row-major view of `R` rows and `C` columns
+type RowMajorView<'a, T, R, C> =
+    MatrixView<'a, T, <R as ToConst>::Const, <C as ToConst>::Const, <C as ToConst>::Const, U1>;
+
+/// Mutable row-major view of `R` rows and `C` columns
+type RowMajorViewMut<'a, T, R, C> =
+    MatrixViewMut<'a, T, <R as ToConst>::Const, <C as ToConst>::Const, <C as ToConst>::Const, U1>;
+
+impl<T: Scalar, N: ArrayLength + ToConst> GenericArray<T, N> {
+    /// Views the array as a [`nalgebra` 0.35](nalgebra_0_35) column vector, without copying.
+    #[inline]
+    pub fn as_na_vector(&self) -> VectorView<'_, T, N::Const> {
+        VectorView::from_slice_generic(self, N::Const::name(), U1::name())
+    }
+
+    /// Views the array as a mutable [`nalgebra` 0.35](nalgebra_0_35) column vector, without copying.
+    #[inline]
+    pub fn as_na_vector_mut(&mut self) -> VectorViewMut<'_, T, N::Const> {
+        VectorViewMut::from_slice_generic(self, N::Const::name(), U1::name())
+    }
+}
+
+impl<T: Scalar, C: ArrayLength + ToConst, R: ArrayLength + ToConst>
+    GenericArray<GenericArray<T, C>, R>
+{
+    /// Views the nested array as a row-major [`nalgebra` 0.35](nalgebra_0_35) matrix
+    /// of `R` rows and `C` columns, without copying.
+    #[inline]
+    pub fn as_na_matrix(&self) -> RowMajorView<'_, T, R, C> {
+        MatrixView::from_slice_with_strides_generic(
+            GenericArray::slice_from_chunks(self),
+            R::Const::name(),
+            C::Const::name(),
+            C::Const::name(),
+            U1::name(),
+        )
+    }
+
+    /// Views the nested array as a mutable row-major [`nalgebra` 0.35](nalgebra_0_35) matrix
+    /// of `R` rows and `C` columns, without copying.
+    #[inline]
+    pub fn as_na_matrix_mut(&mut self) -> RowMajorViewMut<'_, T, R, C> {
+        MatrixViewMut::from_slice_with_strides_generic(
+            GenericArray::slice_from_chunks_mut(self),
+            R::Const::name(),
+            C::Const::name(),
+            C::Const::name(),
+            U1::name(),
+        )
+    }
+}
+
+impl<'a, T: Scalar, N: ArrayLength + ToConst> From<&'a GenericArray<T, N>>
+    for VectorView<'a, T, N::Const>
+{
+    #[inline]
+    fn from(array: &'a GenericArray<T, N>) -> Self {
+        array.as_na_vector()
+    }
+}
+
+impl<'a, T: Scalar, N: ArrayLength + ToConst> From<&'a mut GenericArray<T, N>>
+    for VectorViewMut<'a, T, N::Const>
+{
+    #[inline]
+    fn from(array: &'a mut GenericArray<T, N>) -> Self {
+        array.as_na_vector_mut()
+    }
+}
+
+impl<'a, T: Scalar, C: ArrayLength + ToConst, R: ArrayLength + ToConst>
+    From<&'a GenericArray<GenericArray<T, C>, R>> for RowMajorView<'a, T, R, C>
+{
+    #[inline]
+    fn from(array: &'a GenericArray<GenericArray<T, C>, R>) -> Self {
+        array.as_na_matrix()
+    }
+}
+
+impl<'a, T: Scalar, C: ArrayLength + ToConst, R: ArrayLength + ToConst>
+    From<&'a mut GenericArray<GenericArray<T, C>, R>> for RowMajorViewMut<'a, T, R, C>
+{
+    #[inline]
+    fn from(array: &'a mut GenericArray<GenericArray<T, C>, R>) -> Self {
+        array.as_na_matrix_mut()
+    }
+}
+
+impl<T, N, const D: usize> From<GenericArray<T, N>> for SVector<T, D>
+where
+    N: ArrayLength + ToConst<Const = Const<D>>,
+{
+    #[inline]
+    fn from(array: GenericArray<T, N>) -> Self {
+        // SAFETY: `N == D`, so `GenericArray<T, N>` has the same layout as `[[T; D]; 1]`
+        SVector::from_array_storage(ArrayStorage(unsafe { const_transmute(array) }))
+    }
+}
+
+impl<T, N, const D: usize> From<SVector<T, D>> for GenericArray<T, N>
+where
+    N: ArrayLength + ToConst<Const = Const<D>>,
+{
+    #[inline]
+    fn from(vector: SVector<T, D>) -> Self {
+        // SAFETY: `N == D`, so `[[T; D]; 1]` has the same layout as `GenericArray<T, N>`
+        unsafe { const_transmute(vector.data.0) }
+    }
+}
+
+impl<T, C, R, const CD: usize, const RD: usize> From<GenericArray<GenericArray<T, C>, R>>
+    for SMatrix<T, RD, CD>
+where
+    T: Scalar,
+    C: ArrayLength + ToConst<Const = Const<CD>>,
+    R: ArrayLength + ToConst<Const = Const<RD>>,
+{
+    #[inline]
+    fn from(array: GenericArray<GenericArray<T, C>, R>) -> Self {
+        SMatrix::from_row_iterator(array.into_iter().flatten())
+    }
+}
+
+impl<T, C, R, const CD: usize, const RD: usize> From<SMatrix<T, RD, CD>>
+    for GenericArray<GenericArray<T, C>, R>
+where
+    T: Scalar,
+    C: ArrayLength + ToConst<Const = Const<CD>>,
+    R: ArrayLength + ToConst<Const = Const<RD>>,
+{
+    #[inline]
+    fn from(matrix: SMatrix<T, RD, CD>) -> Self {
+        // the transpose is stored as `RD` columns of `CD` elements, which are our rows
+        //
+        // SAFETY: `[[T; CD]; RD]` has the same layout as `GenericArray<GenericArray<T, C>, R>`
+        unsafe { const_transmute(matrix.transpose().data.0) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra_0_35::{Matrix2x3, SMatrix, SVector, Vector3, VectorView};
+
+    use crate::typenum::U3;
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn vector_views() {
+        let mut state = arr![1.0f64, 2.0, 3.0];
+
+        let view = state.as_na_vector();
+        assert_eq!(view.dot(&Vector3::new(1.0, 1.0, 1.0)), 6.0);
+        assert_eq!(view, Vector3::new(1.0, 2.0, 3.0));
+
+        let view: VectorView<f64, _> = (&state).into();
+        assert_eq!(view.sum(), 6.0);
+
+        state.as_na_vector_mut().add_scalar_mut(1.0);
+        assert_eq!(state, arr![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn matrix_views() {
+        let mut m = arr![arr![1, 2, 3], arr![4, 5, 6]];
+
+        let view = m.as_na_matrix();
+        assert_eq!(view.shape(), (2, 3));
+        assert_eq!(view, Matrix2x3::new(1, 2, 3, 4, 5, 6));
+        assert_eq!(view[(1, 0)], 4);
+        assert_eq!(view.row(1), Matrix2x3::new(1, 2, 3, 4, 5, 6).row(1));
+
+        m.as_na_matrix_mut()[(0, 2)] = 9;
+        m.as_na_matrix_mut().row_mut(1).fill(0);
+        assert_eq!(m, arr![arr![1, 2, 9], arr![0, 0, 0]]);
+    }
+
+    #[test]
+    fn owned() {
+        let v: SVector<u32, 3> = arr![1, 2, 3].into();
+        assert_eq!(v, Vector3::new(1, 2, 3));
+
+        let back: GenericArray<u32, U3> = v.into();
+        assert_eq!(back, arr![1, 2, 3]);
+
+        let m: SMatrix<i32, 2, 3> = arr![arr![1, 2, 3], arr![4, 5, 6]].into();
+        assert_eq!(m, Matrix2x3::new(1, 2, 3, 4, 5, 6));
+
+        let back: GenericArray<GenericArray<i32, U3>, _> = m.into();
+        assert_eq!(back, arr![arr![1, 2, 3], arr![4, 5, 6]]);
+    }
+}
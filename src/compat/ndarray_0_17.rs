@@ -0,0 +1,130 @@
+//! Views and conversions between `GenericArray` and `ndarray` 0.17 arrays.
+//!
+//! One-dimensional views are already provided by `ndarray` for anything implementing
+//! `AsRef<[T]>`/`AsMut<[T]>`, so only `ArrayView1::from(&array)` is needed for those.
+//!
+//! A nested `GenericArray<GenericArray<T, C>, R>` is taken to be `R` rows of `C` columns,
+//! the same as `[[T; C]; R]`.
+
+use ndarray_0_17::{Array1, Array2, ArrayView1, ArrayView2, ArrayViewMut1, ArrayViewMut2, Ix2};
+
+use crate::{ArrayLength, GenericArray};
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Views the array as a one-dimensional [`ndarray` 0.17](ndarray_0_17) array, without copying.
+    #[inline]
+    pub fn as_nd_view(&self) -> ArrayView1<'_, T> {
+        ArrayView1::from(self.as_slice())
+    }
+
+    /// Views the array as a mutable one-dimensional [`ndarray` 0.17](ndarray_0_17) array,
+    /// without copying.
+    #[inline]
+    pub fn as_nd_view_mut(&mut self) -> ArrayViewMut1<'_, T> {
+        ArrayViewMut1::from(self.as_mut_slice())
+    }
+}
+
+impl<T, C: ArrayLength, R: ArrayLength> GenericArray<GenericArray<T, C>, R> {
+    /// Views the nested array as a two-dimensional [`ndarray` 0.17](ndarray_0_17) array
+    /// of `R` rows and `C` columns, without copying.
+    #[inline]
+    pub fn as_nd_view2(&self) -> ArrayView2<'_, T> {
+        let shape = Ix2(R::USIZE, C::USIZE);
+
+        // SAFETY: the `R * C` elements are contiguous in row-major order
+        unsafe { ArrayView2::from_shape_ptr(shape, self.as_ptr().cast()) }
+    }
+
+    /// Views the nested array as a mutable two-dimensional [`ndarray` 0.17](ndarray_0_17) array
+    /// of `R` rows and `C` columns, without copying.
+    #[inline]
+    pub fn as_nd_view2_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        let shape = Ix2(R::USIZE, C::USIZE);
+
+        // SAFETY: the `R * C` elements are contiguous in row-major order
+        unsafe { ArrayViewMut2::from_shape_ptr(shape, self.as_mut_ptr().cast()) }
+    }
+}
+
+impl<'a, T, C: ArrayLength, R: ArrayLength> From<&'a GenericArray<GenericArray<T, C>, R>>
+    for ArrayView2<'a, T>
+{
+    #[inline]
+    fn from(array: &'a GenericArray<GenericArray<T, C>, R>) -> Self {
+        array.as_nd_view2()
+    }
+}
+
+impl<'a, T, C: ArrayLength, R: ArrayLength> From<&'a mut GenericArray<GenericArray<T, C>, R>>
+    for ArrayViewMut2<'a, T>
+{
+    #[inline]
+    fn from(array: &'a mut GenericArray<GenericArray<T, C>, R>) -> Self {
+        array.as_nd_view2_mut()
+    }
+}
+
+impl<T, N: ArrayLength> From<GenericArray<T, N>> for Array1<T> {
+    #[inline]
+    fn from(array: GenericArray<T, N>) -> Self {
+        Array1::from_iter(array)
+    }
+}
+
+impl<T, C: ArrayLength, R: ArrayLength> From<GenericArray<GenericArray<T, C>, R>> for Array2<T> {
+    #[inline]
+    fn from(array: GenericArray<GenericArray<T, C>, R>) -> Self {
+        let shape = Ix2(R::USIZE, C::USIZE);
+
+        // the vector always holds exactly `R * C` elements
+        Array2::from_shape_vec(shape, array.into_iter().flatten().collect()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray_0_17::{aview1, aview2, Array1, Array2, ArrayView1, ArrayView2, ArrayViewMut1};
+
+    use crate::typenum::{U0, U3};
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn views_1d() {
+        let mut a = arr![1.0f64, 2.0, 3.0];
+
+        assert_eq!(a.as_nd_view().dot(&aview1(&[1.0, 1.0, 1.0])), 6.0);
+        assert_eq!(ArrayView1::from(&a), aview1(&[1.0, 2.0, 3.0]));
+
+        a.as_nd_view_mut().mapv_inplace(|x| x * 2.0);
+        ArrayViewMut1::from(&mut a)[0] = 0.0;
+        assert_eq!(a, arr![0.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn views_2d() {
+        let mut m = arr![arr![1, 2, 3], arr![4, 5, 6]];
+
+        let view = m.as_nd_view2();
+        assert_eq!(view, aview2(&[[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(view.row(1), aview1(&[4, 5, 6]));
+        assert_eq!(view.column(2), aview1(&[3, 6]));
+        assert_eq!(ArrayView2::from(&m), aview2(&[[1, 2, 3], [4, 5, 6]]));
+
+        m.as_nd_view2_mut().row_mut(0).fill(0);
+        m.as_nd_view2_mut()[[1, 1]] = 9;
+        assert_eq!(m, arr![arr![0, 0, 0], arr![4, 9, 6]]);
+
+        let empty = GenericArray::<GenericArray<u8, U3>, U0>::default();
+        assert_eq!(empty.as_nd_view2().shape(), &[0, 3]);
+    }
+
+    #[test]
+    fn owned() {
+        let a: Array1<i32> = arr![1, 2, 3].into();
+        assert_eq!(a, aview1(&[1, 2, 3]));
+
+        let m: Array2<i32> = arr![arr![1, 2, 3], arr![4, 5, 6]].into();
+        assert_eq!(m, aview2(&[[1, 2, 3], [4, 5, 6]]));
+    }
+}
@@ -0,0 +1,114 @@
+use core::marker::PhantomData;
+
+use tinyvec_1::{Array, ArrayVec};
+
+use crate::{ArrayLength, ExtendFromGenericArray, GenericArray, LengthError};
+
+/// Compile-time check that the backing array `A` can hold `N` elements
+struct CapacityAsserter<N, A>(PhantomData<(N, A)>);
+
+impl<N: ArrayLength, A: Array> CapacityAsserter<N, A> {
+    const ASSERT_CAPACITY: () = {
+        if N::USIZE > A::CAPACITY {
+            panic!("GenericArray is longer than the vector capacity");
+        }
+    };
+}
+
+/// Succeeds only if the vector holds exactly `N` elements, such as a full `ArrayVec<[T; N]>`.
+impl<A: Array, N: ArrayLength> TryFrom<ArrayVec<A>> for GenericArray<A::Item, N> {
+    type Error = LengthError;
+
+    #[inline]
+    fn try_from(vec: ArrayVec<A>) -> Result<Self, Self::Error> {
+        if vec.len() != N::USIZE {
+            return Err(LengthError);
+        }
+
+        GenericArray::try_from_iter(vec)
+    }
+}
+
+/// The capacity of `A` must be at least `N`, which is checked at compile time.
+impl<A: Array, N: ArrayLength> From<GenericArray<A::Item, N>> for ArrayVec<A> {
+    #[inline]
+    fn from(array: GenericArray<A::Item, N>) -> Self {
+        let () = CapacityAsserter::<N, A>::ASSERT_CAPACITY;
+
+        let mut vec = ArrayVec::new();
+        vec.extend(array);
+        vec
+    }
+}
+
+impl<A: Array> ExtendFromGenericArray<A::Item> for ArrayVec<A> {
+    #[inline]
+    fn extend_from_generic_array<N: ArrayLength>(
+        &mut self,
+        array: GenericArray<A::Item, N>,
+    ) -> Result<(), GenericArray<A::Item, N>> {
+        if self.capacity() - self.len() < N::USIZE {
+            return Err(array);
+        }
+
+        self.extend(array);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinyvec_1::{array_vec, ArrayVec};
+
+    use crate::typenum::{U0, U4};
+    use crate::{arr, ExtendFromGenericArray, GenericArray, LengthError};
+
+    #[test]
+    fn try_from_vec() {
+        let vec = ArrayVec::from([1u8, 2, 3, 4]);
+        assert_eq!(GenericArray::try_from(vec).unwrap(), arr![1u8, 2, 3, 4]);
+
+        // a larger capacity is fine, only the length must match
+        let vec = array_vec!([u8; 8] => 1, 2, 3);
+        assert_eq!(GenericArray::try_from(vec).unwrap(), arr![1u8, 2, 3]);
+
+        let vec = array_vec!([u8; 4] => 1, 2, 3);
+        assert!(matches!(
+            GenericArray::<u8, U4>::try_from(vec),
+            Err(LengthError)
+        ));
+
+        let vec = ArrayVec::<[u8; 4]>::new();
+        assert!(GenericArray::<u8, U0>::try_from(vec).is_ok());
+    }
+
+    #[test]
+    fn from_array() {
+        let vec: ArrayVec<[u8; 4]> = arr![1, 2, 3, 4].into();
+        assert_eq!(vec.len(), vec.capacity());
+        assert_eq!(vec[..], [1, 2, 3, 4]);
+
+        let vec: ArrayVec<[u8; 6]> = arr![1, 2, 3].into();
+        assert_eq!(vec[..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn extend() {
+        let mut vec = array_vec!([u8; 6] => 0);
+
+        assert_eq!(vec.extend_from_generic_array(arr![1, 2, 3]), Ok(()));
+        assert_eq!(vec[..], [0, 1, 2, 3]);
+
+        assert_eq!(
+            vec.extend_from_generic_array(arr![4, 5, 6]),
+            Err(arr![4, 5, 6])
+        );
+        assert_eq!(vec[..], [0, 1, 2, 3]);
+
+        assert_eq!(vec.extend_from_generic_array(arr![4, 5]), Ok(()));
+        assert_eq!(vec.len(), vec.capacity());
+
+        let empty = GenericArray::<u8, U0>::default();
+        assert_eq!(vec.extend_from_generic_array(empty), Ok(()));
+    }
+}
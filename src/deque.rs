@@ -0,0 +1,664 @@
+//! Fixed-capacity double-ended queue backed by a [`GenericArray`].
+//!
+//! [`GenericDeque<T, N>`](GenericDeque) is a ring buffer holding up to `N` elements inline,
+//! without any heap allocation.
+//!
+//! ```rust
+//! use generic_array::{deque::GenericDeque, typenum::U3};
+//!
+//! let mut queue = GenericDeque::<i32, U3>::new();
+//! queue.push_back(1).unwrap();
+//! queue.push_back(2).unwrap();
+//! queue.push_front(0).unwrap();
+//!
+//! // full, so the value is handed back
+//! assert_eq!(queue.push_back(3), Err(3));
+//!
+//! // or make room by overwriting the front element
+//! assert_eq!(queue.push_back_overwrite(3), Some(0));
+//!
+//! assert!(queue.iter().eq(&[1, 2, 3]));
+//! assert_eq!(queue.pop_front(), Some(1));
+//! ```
+
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
+use core::{fmt, ptr, slice};
+
+use crate::internal::SliceDropGuard;
+use crate::{ArrayLength, GenericArray};
+
+/// A fixed-capacity double-ended queue, implemented as a ring buffer over a
+/// `GenericArray<MaybeUninit<T>, N>`.
+///
+/// Pushing to a full queue fails and hands the value back, unless one of the
+/// `*_overwrite` methods is used, which instead evict the element at the opposite end.
+pub struct GenericDeque<T, N: ArrayLength> {
+    // Invariants: head < N (or head == 0 when N == 0), len <= N
+    // Only the `len` elements starting at physical index `head`, wrapping around, are initialized.
+    buf: GenericArray<MaybeUninit<T>, N>,
+    head: usize,
+    len: usize,
+}
+
+impl<T, N: ArrayLength> GenericDeque<T, N> {
+    /// Creates an empty deque.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        GenericDeque {
+            buf: GenericArray::uninit(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of elements the deque can hold, `N`.
+    #[inline(always)]
+    pub const fn capacity() -> usize {
+        N::USIZE
+    }
+
+    /// Returns the number of elements in the deque.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the deque contains `N` elements.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len == N::USIZE
+    }
+
+    /// Maps a logical index `< 2 * N` onto a physical index into `buf`.
+    #[inline(always)]
+    const fn wrap(index: usize) -> usize {
+        if index >= N::USIZE {
+            index - N::USIZE
+        } else {
+            index
+        }
+    }
+
+    #[inline(always)]
+    fn ptr(&self) -> *const T {
+        self.buf.as_ptr() as *const T
+    }
+
+    #[inline(always)]
+    fn mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr() as *mut T
+    }
+
+    /// Raw slices over the `len` elements starting at physical index `head`.
+    ///
+    /// # Safety
+    ///
+    /// `head < N` and `len <= N` must hold.
+    #[inline(always)]
+    unsafe fn raw_slices(&mut self, head: usize, len: usize) -> (*mut [T], *mut [T]) {
+        let base = self.mut_ptr();
+        let first = len.min(N::USIZE - head);
+
+        (
+            ptr::slice_from_raw_parts_mut(base.add(head), first),
+            ptr::slice_from_raw_parts_mut(base, len - first),
+        )
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// Returns the value back as `Err` if the deque is full.
+    #[inline]
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        let tail = Self::wrap(self.head + self.len);
+        unsafe { self.mut_ptr().add(tail).write(value) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// Returns the value back as `Err` if the deque is full.
+    #[inline]
+    pub fn push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        let head = Self::wrap(self.head + N::USIZE - 1);
+        unsafe { self.mut_ptr().add(head).write(value) };
+        self.head = head;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Appends an element to the back of the deque, removing and returning the
+    /// front element first if the deque is full.
+    ///
+    /// If `N` is zero, the value itself is returned.
+    #[inline]
+    pub fn push_back_overwrite(&mut self, value: T) -> Option<T> {
+        if N::USIZE == 0 {
+            return Some(value);
+        }
+
+        let evicted = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+
+        if self.push_back(value).is_err() {
+            unreachable!("GenericDeque has space after evicting an element");
+        }
+
+        evicted
+    }
+
+    /// Prepends an element to the front of the deque, removing and returning the
+    /// back element first if the deque is full.
+    ///
+    /// If `N` is zero, the value itself is returned.
+    #[inline]
+    pub fn push_front_overwrite(&mut self, value: T) -> Option<T> {
+        if N::USIZE == 0 {
+            return Some(value);
+        }
+
+        let evicted = if self.is_full() {
+            self.pop_back()
+        } else {
+            None
+        };
+
+        if self.push_front(value).is_err() {
+            unreachable!("GenericDeque has space after evicting an element");
+        }
+
+        evicted
+    }
+
+    /// Removes and returns the front element, or `None` if the deque is empty.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let head = self.head;
+        self.head = Self::wrap(head + 1);
+        self.len -= 1;
+
+        // SAFETY: `head` was the first live element, and is no longer tracked as live
+        Some(unsafe { ptr::read(self.ptr().add(head)) })
+    }
+
+    /// Removes and returns the back element, or `None` if the deque is empty.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        let tail = Self::wrap(self.head + self.len);
+
+        // SAFETY: `tail` was the last live element, and is no longer tracked as live
+        Some(unsafe { ptr::read(self.ptr().add(tail)) })
+    }
+
+    /// Returns a reference to the element at `index` from the front, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(unsafe { &*self.ptr().add(Self::wrap(self.head + index)) })
+    }
+
+    /// Returns a mutable reference to the element at `index` from the front, or `None` if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let physical = Self::wrap(self.head + index);
+        Some(unsafe { &mut *self.mut_ptr().add(physical) })
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a mutable reference to the front element, or `None` if the deque is empty.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Returns a mutable reference to the back element, or `None` if the deque is empty.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Returns the contents of the deque, in order, as a pair of slices.
+    ///
+    /// If the elements do not wrap around the end of the underlying array,
+    /// the second slice is empty.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let first = self.len.min(N::USIZE - self.head);
+
+        // SAFETY: both ranges are in bounds and contain only live elements
+        unsafe {
+            (
+                slice::from_raw_parts(self.ptr().add(self.head), first),
+                slice::from_raw_parts(self.ptr(), self.len - first),
+            )
+        }
+    }
+
+    /// Returns the contents of the deque, in order, as a pair of mutable slices.
+    ///
+    /// If the elements do not wrap around the end of the underlying array,
+    /// the second slice is empty.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        // SAFETY: both ranges are disjoint, in bounds and contain only live elements
+        unsafe {
+            let (a, b) = self.raw_slices(self.head, self.len);
+            (&mut *a, &mut *b)
+        }
+    }
+
+    /// Returns a front-to-back iterator over the elements.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (a, b) = self.as_slices();
+
+        Iter {
+            a: a.iter(),
+            b: b.iter(),
+        }
+    }
+
+    /// Returns a front-to-back iterator that allows modifying each element.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (a, b) = self.as_mut_slices();
+
+        IterMut {
+            a: a.iter_mut(),
+            b: b.iter_mut(),
+        }
+    }
+
+    /// Removes all elements from the deque, returning them front-to-back as an iterator.
+    ///
+    /// Any elements not consumed by the iterator are dropped when it is dropped.
+    /// If the iterator is leaked (e.g. with [`core::mem::forget`]), the remaining elements
+    /// are leaked too, but the deque is still left empty.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, T, N> {
+        let (head, remaining) = (self.head, self.len);
+
+        // the deque no longer owns the elements, so leaking `Drain` only leaks them
+        self.head = 0;
+        self.len = 0;
+
+        Drain {
+            deque: self,
+            head,
+            remaining,
+        }
+    }
+
+    /// Removes and drops all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        drop(self.drain());
+    }
+}
+
+impl<T, N: ArrayLength> Drop for GenericDeque<T, N> {
+    fn drop(&mut self) {
+        unsafe {
+            let (front, back) = self.raw_slices(self.head, self.len);
+
+            // if dropping `front` panics, `back` is still dropped by the guard
+            let _back = SliceDropGuard(back);
+            ptr::drop_in_place(front);
+        }
+    }
+}
+
+impl<T, N: ArrayLength> Default for GenericDeque<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, N: ArrayLength> Clone for GenericDeque<T, N> {
+    fn clone(&self) -> Self {
+        // if `clone` panics, `out` drops the elements cloned so far
+        let mut out = GenericDeque::new();
+
+        for value in self.iter() {
+            let _ = out.push_back(value.clone());
+        }
+
+        out
+    }
+}
+
+impl<T: fmt::Debug, N: ArrayLength> fmt::Debug for GenericDeque<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, N: ArrayLength> PartialEq for GenericDeque<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, N: ArrayLength> Eq for GenericDeque<T, N> {}
+
+impl<T: Hash, N: ArrayLength> Hash for GenericDeque<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // length-prefixed, like slices
+        state.write_usize(self.len);
+        self.iter().for_each(|value| value.hash(state));
+    }
+}
+
+impl<T, N: ArrayLength> From<GenericArray<T, N>> for GenericDeque<T, N> {
+    /// Creates a full deque from the elements of the array, in order.
+    #[inline]
+    fn from(array: GenericArray<T, N>) -> Self {
+        GenericDeque {
+            // SAFETY: `GenericArray<MaybeUninit<T>, N>` has the same layout as `GenericArray<T, N>`
+            buf: unsafe { crate::const_transmute(array) },
+            head: 0,
+            len: N::USIZE,
+        }
+    }
+}
+
+impl<'a, T, N: ArrayLength> IntoIterator for &'a GenericDeque<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, N: ArrayLength> IntoIterator for &'a mut GenericDeque<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, N: ArrayLength> IntoIterator for GenericDeque<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter { deque: self }
+    }
+}
+
+macro_rules! impl_slice_pair_iter {
+    ($name:ident, $item:ty) => {
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<$item> {
+                match self.a.next() {
+                    Some(value) => Some(value),
+                    None => self.b.next(),
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.a.len() + self.b.len();
+                (len, Some(len))
+            }
+
+            #[inline]
+            fn fold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, $item) -> B,
+            {
+                self.a.chain(self.b).fold(init, f)
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<$item> {
+                match self.b.next_back() {
+                    Some(value) => Some(value),
+                    None => self.a.next_back(),
+                }
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+/// Front-to-back iterator over references to the elements of a [`GenericDeque`].
+///
+/// Created by [`GenericDeque::iter`].
+pub struct Iter<'a, T> {
+    a: slice::Iter<'a, T>,
+    b: slice::Iter<'a, T>,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter {
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&self.a.as_slice())
+            .field(&self.b.as_slice())
+            .finish()
+    }
+}
+
+impl_slice_pair_iter!(Iter, &'a T);
+
+/// Front-to-back iterator over mutable references to the elements of a [`GenericDeque`].
+///
+/// Created by [`GenericDeque::iter_mut`].
+pub struct IterMut<'a, T> {
+    a: slice::IterMut<'a, T>,
+    b: slice::IterMut<'a, T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IterMut")
+            .field(&self.a.as_slice())
+            .field(&self.b.as_slice())
+            .finish()
+    }
+}
+
+impl_slice_pair_iter!(IterMut, &'a mut T);
+
+/// An iterator that moves elements out of a [`GenericDeque`], front-to-back.
+///
+/// Created by the [`IntoIterator`] implementation on [`GenericDeque`].
+pub struct IntoIter<T, N: ArrayLength> {
+    deque: GenericDeque<T, N>,
+}
+
+impl<T: Clone, N: ArrayLength> Clone for IntoIter<T, N> {
+    fn clone(&self) -> Self {
+        IntoIter {
+            deque: self.deque.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, N: ArrayLength> fmt::Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.deque).finish()
+    }
+}
+
+impl<T, N: ArrayLength> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len, Some(self.deque.len))
+    }
+}
+
+impl<T, N: ArrayLength> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T, N: ArrayLength> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, N: ArrayLength> FusedIterator for IntoIter<T, N> {}
+
+/// A draining iterator over the elements of a [`GenericDeque`].
+///
+/// Created by [`GenericDeque::drain`].
+pub struct Drain<'a, T, N: ArrayLength> {
+    // Invariants: the `remaining` elements starting at physical index `head` are live,
+    // and owned by the `Drain` rather than the (already emptied) deque.
+    deque: &'a mut GenericDeque<T, N>,
+    head: usize,
+    remaining: usize,
+}
+
+impl<T: fmt::Debug, N: ArrayLength> fmt::Debug for Drain<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let first = self.remaining.min(N::USIZE - self.head);
+
+        // SAFETY: both ranges are in bounds and contain only live elements
+        let (a, b) = unsafe {
+            (
+                slice::from_raw_parts(self.deque.ptr().add(self.head), first),
+                slice::from_raw_parts(self.deque.ptr(), self.remaining - first),
+            )
+        };
+
+        f.debug_list().entries(a.iter().chain(b)).finish()
+    }
+}
+
+impl<T, N: ArrayLength> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let head = self.head;
+        self.head = GenericDeque::<T, N>::wrap(head + 1);
+        self.remaining -= 1;
+
+        Some(unsafe { ptr::read(self.deque.ptr().add(head)) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, N: ArrayLength> DoubleEndedIterator for Drain<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let tail = GenericDeque::<T, N>::wrap(self.head + self.remaining);
+
+        Some(unsafe { ptr::read(self.deque.ptr().add(tail)) })
+    }
+}
+
+impl<T, N: ArrayLength> ExactSizeIterator for Drain<'_, T, N> {}
+
+impl<T, N: ArrayLength> FusedIterator for Drain<'_, T, N> {}
+
+impl<T, N: ArrayLength> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        let (head, remaining) = (self.head, self.remaining);
+        self.remaining = 0;
+
+        unsafe {
+            let (front, back) = self.deque.raw_slices(head, remaining);
+
+            // if dropping `front` panics, `back` is still dropped by the guard
+            let _back = SliceDropGuard(back);
+            ptr::drop_in_place(front);
+        }
+    }
+}
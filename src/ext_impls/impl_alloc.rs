@@ -1,16 +1,69 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
 
 use crate::{ArrayLength, GenericArray, IntrusiveArrayBuilder, LengthError};
 
 use core::{alloc::Layout, mem::MaybeUninit, ptr};
 
-struct IntrusiveBoxedArrayBuilder<T, N: ArrayLength> {
+pub(crate) struct IntrusiveBoxedArrayBuilder<T, N: ArrayLength> {
     layout: Layout,
     ptr: *mut GenericArray<MaybeUninit<T>, N>,
     position: usize,
 }
 
 impl<T, N: ArrayLength> IntrusiveBoxedArrayBuilder<T, N> {
+    /// Allocates an uninitialized array on the heap, or returns `None` if allocation fails.
+    #[inline]
+    pub(crate) fn try_new() -> Option<Self> {
+        let layout = Layout::new::<GenericArray<MaybeUninit<T>, N>>();
+
+        let ptr: *mut GenericArray<MaybeUninit<T>, N> = if layout.size() == 0 {
+            ptr::NonNull::dangling().as_ptr()
+        } else {
+            let p = unsafe { alloc::alloc::alloc(layout) };
+
+            if p.is_null() {
+                return None;
+            }
+
+            p.cast()
+        };
+
+        Some(IntrusiveBoxedArrayBuilder {
+            layout,
+            ptr,
+            position: 0,
+        })
+    }
+
+    /// Like [`try_new`](Self::try_new), but aborts via `handle_alloc_error` if allocation fails.
+    #[inline]
+    pub(crate) fn new() -> Self {
+        match Self::try_new() {
+            Some(builder) => builder,
+            None => alloc::alloc::handle_alloc_error(Layout::new::<GenericArray<T, N>>()),
+        }
+    }
+
+    /// Returns the whole uninitialized array as a slice, along with the number of
+    /// initialized elements at the start of it, which must be kept up to date.
+    #[cfg(feature = "rayon")]
+    #[inline(always)]
+    pub(crate) unsafe fn slice_position(&'_ mut self) -> (&'_ mut [MaybeUninit<T>], &'_ mut usize) {
+        ((&mut *self.ptr).as_mut_slice(), &mut self.position)
+    }
+
+    /// Returns the whole uninitialized array, along with the number of initialized
+    /// elements at the start of it, which must be kept up to date.
+    #[inline(always)]
+    unsafe fn array_position(
+        &'_ mut self,
+    ) -> (&'_ mut MaybeUninit<GenericArray<T, N>>, &'_ mut usize) {
+        (&mut *self.ptr.cast(), &mut self.position)
+    }
+
     #[inline(always)]
     unsafe fn iter_position(
         &'_ mut self,
@@ -19,7 +72,7 @@ impl<T, N: ArrayLength> IntrusiveBoxedArrayBuilder<T, N> {
     }
 
     #[inline(always)]
-    unsafe fn finish(self) -> Box<GenericArray<T, N>> {
+    pub(crate) unsafe fn finish(self) -> Box<GenericArray<T, N>> {
         debug_assert!(self.position == N::USIZE);
         let ptr = self.ptr;
         core::mem::forget(self);
@@ -129,25 +182,188 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
     where
         I: IntoIterator<Item = T>,
     {
-        let mut iter = iter.into_iter();
+        let iter = GenericArray::<T, N>::check_iter_len(iter)?;
+
+        let mut builder = IntrusiveBoxedArrayBuilder::new();
 
-        // pre-checks
-        match iter.size_hint() {
-            // if the lower bound is greater than N, array will overflow
-            (n, _) if n > N::USIZE => return Err(LengthError),
-            // if the upper bound is smaller than N, array cannot be filled
-            (_, Some(n)) if n < N::USIZE => return Err(LengthError),
-            _ => {}
+        unsafe {
+            let (array, position) = builder.array_position();
+
+            GenericArray::try_fill_from_iter(array, iter)?;
+            *position = N::USIZE;
+
+            Ok(builder.finish())
         }
+    }
+}
 
-        let mut v = Vec::with_capacity(N::USIZE);
-        v.extend((&mut iter).take(N::USIZE));
+#[rustversion::since(1.82)]
+#[inline(always)]
+fn rc_new_uninit<T>() -> Rc<MaybeUninit<T>> {
+    Rc::new_uninit()
+}
 
-        if v.len() != N::USIZE || iter.next().is_some() {
+// `new_uninit` was stabilized in Rust 1.82. Before that, moving an uninitialized value in
+// is usually optimized away, but debug builds may still reserve stack space for it.
+#[rustversion::before(1.82)]
+#[inline(always)]
+fn rc_new_uninit<T>() -> Rc<MaybeUninit<T>> {
+    Rc::new(MaybeUninit::uninit())
+}
+
+#[cfg(target_has_atomic = "ptr")]
+#[rustversion::since(1.82)]
+#[inline(always)]
+fn arc_new_uninit<T>() -> Arc<MaybeUninit<T>> {
+    Arc::new_uninit()
+}
+
+#[cfg(target_has_atomic = "ptr")]
+#[rustversion::before(1.82)]
+#[inline(always)]
+fn arc_new_uninit<T>() -> Arc<MaybeUninit<T>> {
+    Arc::new(MaybeUninit::uninit())
+}
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Like [`GenericArray::try_from_iter`] but returns an `Rc<GenericArray<T, N>>` instead.
+    ///
+    /// The elements are written directly into the `Rc` allocation.
+    pub fn try_rc_from_iter<I>(iter: I) -> Result<Rc<GenericArray<T, N>>, LengthError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = GenericArray::<T, N>::check_iter_len(iter)?;
+
+        let mut rc = rc_new_uninit::<GenericArray<T, N>>();
+
+        // SAFETY: the `Rc` was just created, so it's unique
+        let array = unsafe { Rc::get_mut(&mut rc).unwrap_unchecked() };
+        GenericArray::try_fill_from_iter(array, iter)?;
+
+        // SAFETY: the array is initialized, and `MaybeUninit<X>` has the same layout as `X`
+        Ok(unsafe { Rc::from_raw(Rc::into_raw(rc).cast()) })
+    }
+
+    /// Like [`GenericSequence::generate`] for `Rc<GenericArray<T, N>>`,
+    /// writing each element directly into the `Rc` allocation.
+    ///
+    /// ```rust
+    /// # use generic_array::{GenericArray, typenum::U4};
+    /// let table = GenericArray::<u32, U4>::generate_rc(|i| 1 << i);
+    ///
+    /// assert_eq!(table.as_slice(), [1, 2, 4, 8]);
+    /// ```
+    #[inline]
+    pub fn generate_rc<F>(f: F) -> Rc<GenericArray<T, N>>
+    where
+        F: FnMut(usize) -> T,
+    {
+        match Self::try_rc_from_iter((0..N::USIZE).map(f)) {
+            Ok(rc) => rc,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Attempts to convert an `Rc<[T]>` into `Rc<GenericArray<T, N>>` without reallocating.
+    ///
+    /// This operation is O(1), constant-time regardless of the array length N.
+    #[inline]
+    pub fn try_from_rc_slice(slice: Rc<[T]>) -> Result<Rc<GenericArray<T, N>>, LengthError> {
+        if slice.len() != N::USIZE {
             return Err(LengthError);
         }
 
-        Ok(GenericArray::try_from_vec(v).unwrap())
+        // SAFETY: `GenericArray<T, N>` has the same layout as `[T]` of length `N`
+        Ok(unsafe { Rc::from_raw(Rc::into_raw(slice) as *const _) })
+    }
+
+    /// Converts an `Rc<GenericArray<T, N>>` into `Rc<[T]>` without reallocating.
+    ///
+    /// This operation is O(1), constant-time regardless of the array length N.
+    #[inline]
+    pub fn into_rc_slice(self: Rc<GenericArray<T, N>>) -> Rc<[T]> {
+        // SAFETY: `Rc::into_raw` points to the array, which has the same layout as `[T]` of length `N`
+        unsafe {
+            Rc::from_raw(ptr::slice_from_raw_parts(
+                Rc::into_raw(self) as *const T,
+                N::USIZE,
+            ))
+        }
+    }
+
+    /// Like [`GenericArray::try_from_iter`] but returns an `Arc<GenericArray<T, N>>` instead.
+    ///
+    /// The elements are written directly into the `Arc` allocation.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn try_arc_from_iter<I>(iter: I) -> Result<Arc<GenericArray<T, N>>, LengthError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = GenericArray::<T, N>::check_iter_len(iter)?;
+
+        let mut arc = arc_new_uninit::<GenericArray<T, N>>();
+
+        // SAFETY: the `Arc` was just created, so it's unique
+        let array = unsafe { Arc::get_mut(&mut arc).unwrap_unchecked() };
+        GenericArray::try_fill_from_iter(array, iter)?;
+
+        // SAFETY: the array is initialized, and `MaybeUninit<X>` has the same layout as `X`
+        Ok(unsafe { Arc::from_raw(Arc::into_raw(arc).cast()) })
+    }
+
+    /// Like [`GenericSequence::generate`] for `Arc<GenericArray<T, N>>`,
+    /// writing each element directly into the `Arc` allocation.
+    ///
+    /// Large read-only tables can be built this way and shared between threads
+    /// without ever being placed on the stack.
+    ///
+    /// ```rust
+    /// # use generic_array::{GenericArray, typenum::{Exp, U2, U16}};
+    /// let table = GenericArray::<u16, Exp<U2, U16>>::generate_arc(|i| (i as u16).reverse_bits());
+    ///
+    /// let shared = table.clone();
+    /// std::thread::spawn(move || assert_eq!(shared[1], 0x8000)).join().unwrap();
+    /// ```
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline]
+    pub fn generate_arc<F>(f: F) -> Arc<GenericArray<T, N>>
+    where
+        F: FnMut(usize) -> T,
+    {
+        match Self::try_arc_from_iter((0..N::USIZE).map(f)) {
+            Ok(arc) => arc,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Attempts to convert an `Arc<[T]>` into `Arc<GenericArray<T, N>>` without reallocating.
+    ///
+    /// This operation is O(1), constant-time regardless of the array length N.
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline]
+    pub fn try_from_arc_slice(slice: Arc<[T]>) -> Result<Arc<GenericArray<T, N>>, LengthError> {
+        if slice.len() != N::USIZE {
+            return Err(LengthError);
+        }
+
+        // SAFETY: `GenericArray<T, N>` has the same layout as `[T]` of length `N`
+        Ok(unsafe { Arc::from_raw(Arc::into_raw(slice) as *const _) })
+    }
+
+    /// Converts an `Arc<GenericArray<T, N>>` into `Arc<[T]>` without reallocating.
+    ///
+    /// This operation is O(1), constant-time regardless of the array length N.
+    #[cfg(target_has_atomic = "ptr")]
+    #[inline]
+    pub fn into_arc_slice(self: Arc<GenericArray<T, N>>) -> Arc<[T]> {
+        // SAFETY: `Arc::into_raw` points to the array, which has the same layout as `[T]` of length `N`
+        unsafe {
+            Arc::from_raw(ptr::slice_from_raw_parts(
+                Arc::into_raw(self) as *const T,
+                N::USIZE,
+            ))
+        }
     }
 }
 
@@ -209,25 +425,7 @@ unsafe impl<T, N: ArrayLength> GenericSequence<T> for Box<GenericArray<T, N>> {
         F: FnMut(usize) -> T,
     {
         unsafe {
-            let layout = Layout::new::<GenericArray<MaybeUninit<T>, N>>();
-
-            let raw_ptr: *mut GenericArray<MaybeUninit<T>, N> = if layout.size() == 0 {
-                ptr::NonNull::dangling().as_ptr()
-            } else {
-                let p = alloc::alloc::alloc(layout);
-
-                if p.is_null() {
-                    alloc::alloc::handle_alloc_error(layout);
-                }
-
-                p.cast()
-            };
-
-            let mut builder = IntrusiveBoxedArrayBuilder {
-                layout,
-                ptr: raw_ptr,
-                position: 0,
-            };
+            let mut builder = IntrusiveBoxedArrayBuilder::new();
 
             {
                 let (builder_iter, position) = builder.iter_position();
@@ -280,24 +478,8 @@ unsafe impl<T, N: ArrayLength> FallibleGenericSequence<T> for Box<GenericArray<T
         F: FnMut(usize) -> Result<T, E>,
     {
         unsafe {
-            let layout = Layout::new::<GenericArray<MaybeUninit<T>, N>>();
-
-            let raw_ptr: *mut GenericArray<MaybeUninit<T>, N> = if layout.size() == 0 {
-                ptr::NonNull::dangling().as_ptr()
-            } else {
-                let p = alloc::alloc::alloc(layout);
-
-                if p.is_null() {
-                    return Err(crate::AllocError);
-                }
-
-                p.cast()
-            };
-
-            let mut builder = IntrusiveBoxedArrayBuilder {
-                layout,
-                ptr: raw_ptr,
-                position: 0,
+            let Some(mut builder) = IntrusiveBoxedArrayBuilder::try_new() else {
+                return Err(crate::AllocError);
             };
 
             let (builder_iter, position) = builder.iter_position();
@@ -0,0 +1,204 @@
+//! Native bincode 2 encoding/decoding implementation
+//!
+//! `GenericArray<T, N>` is encoded exactly like `[T; N]`, which is also identical to the
+//! output of bincode 1 through the serde implementation: the `N` elements back to back,
+//! without a length prefix.
+//!
+//! These mirror bincode's own impls for `[T; N]`, including the raw bytes fast path for `u8`.
+
+use core::{mem, ptr};
+
+use bincode_2::de::read::Reader;
+use bincode_2::de::{BorrowDecoder, Decoder};
+use bincode_2::enc::write::Writer;
+use bincode_2::enc::Encoder;
+use bincode_2::error::{DecodeError, EncodeError};
+use bincode_2::{BorrowDecode, Decode, Encode};
+
+use crate::{sequence::FallibleGenericSequence as _, ArrayLength, GenericArray};
+
+impl<T, N: ArrayLength> Encode for GenericArray<T, N>
+where
+    T: Encode,
+{
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        if unty::type_equal::<T, u8>() {
+            // SAFETY: `T` is `u8`
+            let bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), N::USIZE) };
+
+            return encoder.writer().write(bytes);
+        }
+
+        for el in self {
+            el.encode(encoder)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the raw bytes of a `u8` array.
+///
+/// # Safety
+///
+/// `T` must be `u8`.
+#[inline]
+unsafe fn read_bytes<T, N: ArrayLength, R: Reader>(
+    reader: &mut R,
+) -> Result<GenericArray<T, N>, DecodeError> {
+    let mut bytes = GenericArray::<u8, N>::default();
+    reader.read(&mut bytes)?;
+
+    // SAFETY: `T` is `u8`. `const_transmute` can't be used here, as its size check would
+    // also be evaluated for the non-`u8` instantiations that never reach this point.
+    Ok(unsafe { ptr::read(&bytes as *const GenericArray<u8, N> as *const GenericArray<T, N>) })
+}
+
+impl<Context, T, N: ArrayLength> Decode<Context> for GenericArray<T, N>
+where
+    T: Decode<Context>,
+{
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        decoder.claim_bytes_read(mem::size_of::<Self>())?;
+
+        if unty::type_equal::<T, u8>() {
+            // SAFETY: `T` is `u8`
+            return unsafe { read_bytes(decoder.reader()) };
+        }
+
+        GenericArray::try_generate(|_| {
+            // See the documentation on `unclaim_bytes_read` as to why this is done here
+            decoder.unclaim_bytes_read(mem::size_of::<T>());
+            T::decode(decoder)
+        })
+        .unwrap_or_else(|e| match e {})
+    }
+}
+
+impl<'de, Context, T, N: ArrayLength> BorrowDecode<'de, Context> for GenericArray<T, N>
+where
+    T: BorrowDecode<'de, Context>,
+{
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        decoder.claim_bytes_read(mem::size_of::<Self>())?;
+
+        if unty::type_equal::<T, u8>() {
+            // SAFETY: `T` is `u8`
+            return unsafe { read_bytes(decoder.reader()) };
+        }
+
+        GenericArray::try_generate(|_| {
+            // See the documentation on `unclaim_bytes_read` as to why this is done here
+            decoder.unclaim_bytes_read(mem::size_of::<T>());
+            T::borrow_decode(decoder)
+        })
+        .unwrap_or_else(|e| match e {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bincode_2::config;
+    use bincode_2::error::DecodeError;
+
+    use crate::typenum::{U0, U3, U4, U5};
+    use crate::{arr, GenericArray};
+
+    #[cfg(feature = "serde")]
+    fn roundtrip<T>(value: &T)
+    where
+        T: bincode_2::Encode
+            + bincode_2::Decode<()>
+            + for<'de> bincode_2::BorrowDecode<'de, ()>
+            + serde_core::Serialize
+            + PartialEq
+            + core::fmt::Debug,
+    {
+        // bincode 2 configured like bincode 1's `serialize`
+        let config = config::legacy();
+
+        let mut buf = [0u8; 64];
+        let len = bincode_2::encode_into_slice(value, &mut buf, config).unwrap();
+
+        // identical wire layout to bincode 1 through serde
+        assert_eq!(&buf[..len], &bincode::serialize(value).unwrap()[..]);
+
+        let (decoded, read): (T, usize) =
+            bincode_2::decode_from_slice(&buf[..len], config).unwrap();
+        assert_eq!(&decoded, value);
+        assert_eq!(read, len);
+
+        let (decoded, read): (T, usize) =
+            bincode_2::borrow_decode_from_slice(&buf[..len], config).unwrap();
+        assert_eq!(&decoded, value);
+        assert_eq!(read, len);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_roundtrip_bincode_1() {
+        roundtrip(&arr![1u8, 2, 3, 4, 5]);
+        roundtrip(&arr![1u32, 0xDEAD_BEEF, 3]);
+        roundtrip(&arr![-1i64, i64::MAX]);
+        roundtrip(&arr![arr![1u16, 2], arr![3, 4]]);
+        roundtrip(&GenericArray::<u32, U0>::default());
+    }
+
+    #[test]
+    fn test_standard_config() {
+        let array = arr![1u32, 300, 70000];
+
+        // same as a core array with varint encoding too
+        let mut a = [0u8; 16];
+        let mut b = [0u8; 16];
+        let len = bincode_2::encode_into_slice(array, &mut a, config::standard()).unwrap();
+        let len_core =
+            bincode_2::encode_into_slice([1u32, 300, 70000], &mut b, config::standard()).unwrap();
+        assert_eq!(a[..len], b[..len_core]);
+
+        let (decoded, _): (GenericArray<u32, U3>, usize) =
+            bincode_2::decode_from_slice(&a[..len], config::standard()).unwrap();
+        assert_eq!(decoded, array);
+
+        let (empty, read): (GenericArray<u8, U0>, usize) =
+            bincode_2::decode_from_slice(&[], config::standard()).unwrap();
+        assert_eq!(empty, GenericArray::default());
+        assert_eq!(read, 0);
+    }
+
+    #[test]
+    fn test_too_short() {
+        let result = bincode_2::decode_from_slice::<GenericArray<u8, U5>, _>(
+            &[1, 2, 3, 4],
+            config::standard(),
+        );
+        assert!(result.is_err());
+
+        let result = bincode_2::decode_from_slice::<GenericArray<u16, U4>, _>(
+            &[1, 2, 3, 4],
+            config::legacy(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_limit() {
+        let config = config::standard().with_limit::<4>();
+
+        let result = bincode_2::decode_from_slice::<GenericArray<u8, U5>, _>(&[0; 5], config);
+        assert!(matches!(result, Err(DecodeError::LimitExceeded)));
+    }
+
+    #[test]
+    fn test_element_error() {
+        let result = bincode_2::decode_from_slice::<GenericArray<bool, U3>, _>(
+            &[1, 0, 2],
+            config::standard(),
+        );
+        assert!(matches!(result, Err(DecodeError::InvalidBooleanValue(2))));
+    }
+}
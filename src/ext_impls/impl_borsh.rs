@@ -0,0 +1,140 @@
+//! Borsh serialization/deserialization implementation
+//!
+//! `GenericArray<T, N>` is encoded exactly like `[T; N]`: the `N` elements back to back,
+//! without a length prefix.
+
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{sequence::FallibleGenericSequence as _, ArrayLength, GenericArray};
+
+impl<T, N: ArrayLength> BorshSerialize for GenericArray<T, N>
+where
+    T: BorshSerialize,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        if let Some(bytes) = T::u8_slice(self) {
+            return writer.write_all(bytes);
+        }
+
+        for el in self {
+            el.serialize(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, N: ArrayLength> BorshDeserialize for GenericArray<T, N>
+where
+    T: BorshDeserialize,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        GenericArray::try_generate(|_| T::deserialize_reader(reader)).unwrap_or_else(|e| match e {})
+    }
+}
+
+#[cfg(feature = "borsh-schema")]
+mod schema {
+    use alloc::collections::BTreeMap;
+    use alloc::format;
+
+    use borsh::schema::{add_definition, Declaration, Definition};
+    use borsh::BorshSchema;
+
+    use crate::{ArrayLength, GenericArray};
+
+    // same schema as `[T; N]`, a fixed-length sequence
+    impl<T, N: ArrayLength> BorshSchema for GenericArray<T, N>
+    where
+        T: BorshSchema,
+    {
+        fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+            let length = N::U64;
+            let definition = Definition::Sequence {
+                length_width: Definition::ARRAY_LENGTH_WIDTH,
+                length_range: length..=length,
+                elements: T::declaration(),
+            };
+            add_definition(Self::declaration(), definition, definitions);
+            T::add_definitions_recursively(definitions);
+        }
+
+        fn declaration() -> Declaration {
+            format!("[{}; {}]", T::declaration(), N::USIZE)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::io::ErrorKind;
+
+    use crate::typenum::{U0, U3, U4};
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn test_roundtrip() {
+        let array = arr![1u32, 2, 3];
+        let bytes = borsh::to_vec(&array).unwrap();
+
+        // same encoding as a core array, with no length prefix
+        assert_eq!(bytes, borsh::to_vec(&[1u32, 2, 3]).unwrap());
+        assert_eq!(bytes.len(), 12);
+
+        assert_eq!(
+            borsh::from_slice::<GenericArray<u32, U3>>(&bytes).unwrap(),
+            array
+        );
+
+        let bytes = borsh::to_vec(&arr![1u8, 2, 3, 4]).unwrap();
+        assert_eq!(bytes, [1, 2, 3, 4]);
+        assert_eq!(
+            borsh::from_slice::<GenericArray<u8, U4>>(&bytes).unwrap(),
+            arr![1, 2, 3, 4]
+        );
+
+        let empty = GenericArray::<u8, U0>::default();
+        assert!(borsh::to_vec(&empty).unwrap().is_empty());
+        assert_eq!(
+            borsh::from_slice::<GenericArray<u8, U0>>(&[]).unwrap(),
+            empty
+        );
+    }
+
+    #[test]
+    fn test_exact_length() {
+        // too short
+        assert!(borsh::from_slice::<GenericArray<u8, U4>>(&[1, 2, 3]).is_err());
+        // trailing bytes are rejected by `from_slice`
+        assert!(borsh::from_slice::<GenericArray<u8, U4>>(&[1, 2, 3, 4, 5]).is_err());
+    }
+
+    #[test]
+    fn test_element_error() {
+        // borsh only accepts 0 or 1 for `bool`
+        let err = borsh::from_slice::<GenericArray<bool, U3>>(&[1, 0, 2]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[cfg(feature = "borsh-schema")]
+    #[test]
+    fn test_schema() {
+        use borsh::schema::{BorshSchemaContainer, Definition};
+
+        let container = BorshSchemaContainer::for_type::<GenericArray<u16, U3>>();
+        assert_eq!(container, BorshSchemaContainer::for_type::<[u16; 3]>());
+
+        assert_eq!(container.declaration(), "[u16; 3]");
+        assert_eq!(
+            container.get_definition("[u16; 3]"),
+            Some(&Definition::Sequence {
+                length_width: Definition::ARRAY_LENGTH_WIDTH,
+                length_range: 3..=3,
+                elements: "u16".into(),
+            })
+        );
+    }
+}
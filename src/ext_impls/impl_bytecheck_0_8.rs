@@ -46,6 +46,65 @@ where
     }
 }
 
+#[cfg(feature = "rkyv-0_8")]
+mod string {
+    use core::{fmt, str};
+
+    use bytecheck_0_8::{
+        rancor::{fail, Fallible, ResultExt, Source},
+        CheckBytes,
+    };
+
+    use crate::string::ArchivedGenericString;
+    use crate::ArrayLength;
+
+    #[derive(Debug)]
+    struct InvalidStringLength {
+        len: usize,
+        capacity: usize,
+    }
+
+    impl fmt::Display for InvalidStringLength {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "GenericString length {} exceeds capacity {} or has non-zero trailing bytes",
+                self.len, self.capacity
+            )
+        }
+    }
+
+    impl core::error::Error for InvalidStringLength {}
+
+    // SAFETY: `check_bytes` only returns `Ok` if `len` is within the capacity, the bytes before
+    // `len` are valid UTF-8 and the bytes after it are zeroed, which are all of the invariants
+    // of `ArchivedGenericString`.
+    unsafe impl<N: ArrayLength, C> CheckBytes<C> for ArchivedGenericString<N>
+    where
+        C: Fallible + ?Sized,
+        C::Error: Source,
+    {
+        #[inline]
+        unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+            // SAFETY: every bit pattern is valid for both the byte array and the archived
+            // length, so the value can be referenced before checking its invariants.
+            let value = unsafe { &*value };
+            let len = value.len.to_native() as usize;
+
+            if len > N::USIZE || value.buf[len..].iter().any(|&b| b != 0) {
+                fail!(InvalidStringLength {
+                    len,
+                    capacity: N::USIZE,
+                });
+            }
+
+            str::from_utf8(&value.buf[..len]).into_error()?;
+
+            Ok(())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bytecheck_0_8 as bytecheck;
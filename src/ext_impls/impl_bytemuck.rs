@@ -1,7 +1,77 @@
-use bytemuck::{Pod, Zeroable};
+//! `bytemuck` trait implementations
+//!
+//! `AnyBitPattern` and `NoUninit` follow from `Pod` through bytemuck's blanket impls, which
+//! also rule out implementing them, or `CheckedBitPattern`, for `GenericArray` directly.
+//! For element types with invalid bit patterns, such as `bool` or fieldless enums, cast to
+//! a slice with `bytemuck::checked` and then to an array with [`GenericArray::try_from_slice`].
 
-use crate::{ArrayLength, GenericArray};
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+use crate::{ArrayLength, Const, GenericArray, IntoArrayLength};
 
 unsafe impl<T: Pod, N: ArrayLength> Pod for GenericArray<T, N> where GenericArray<T, N>: Copy {}
 
 unsafe impl<T: Zeroable, N: ArrayLength> Zeroable for GenericArray<T, N> {}
+
+// SAFETY: `GenericArray<T, N>` is `repr(transparent)` over a `T` array with the same layout
+// as `[T; U]` when `N` is `U`.
+unsafe impl<T, N: ArrayLength, const U: usize> TransparentWrapper<[T; U]> for GenericArray<T, N> where
+    Const<U>: IntoArrayLength<ArrayLength = N>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::{CheckedBitPattern, TransparentWrapper};
+
+    use crate::typenum::{U2, U3};
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn test_transparent_wrapper() {
+        let array = [1u16, 2, 3];
+
+        let wrapped: &GenericArray<u16, U3> = TransparentWrapper::wrap_ref(&array);
+        assert_eq!(*wrapped, arr![1, 2, 3]);
+
+        let mut arrays = [[1u8, 2], [3, 4]];
+        let wrapped: &mut [GenericArray<u8, U2>] = TransparentWrapper::wrap_slice_mut(&mut arrays);
+        wrapped[1][0] = 9;
+        assert_eq!(arrays, [[1, 2], [9, 4]]);
+
+        let peeled: [u16; 3] = GenericArray::peel(arr![4u16, 5, 6]);
+        assert_eq!(peeled, [4, 5, 6]);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(u8)]
+    enum Kind {
+        A = 1,
+        B = 2,
+    }
+
+    unsafe impl CheckedBitPattern for Kind {
+        type Bits = u8;
+
+        fn is_valid_bit_pattern(bits: &u8) -> bool {
+            matches!(*bits, 1 | 2)
+        }
+    }
+
+    #[test]
+    fn test_checked_cast() {
+        let bytes = [1u8, 2, 1];
+
+        let kinds = bytemuck::checked::try_cast_slice::<u8, Kind>(&bytes).unwrap();
+        let kinds = GenericArray::<Kind, U3>::try_from_slice(kinds).unwrap();
+        assert_eq!(*kinds, arr![Kind::A, Kind::B, Kind::A]);
+
+        assert!(bytemuck::checked::try_cast_slice::<u8, Kind>(&[1, 3, 1]).is_err());
+
+        let bools = bytemuck::checked::try_cast_slice::<u8, bool>(&[1, 0]).unwrap();
+        assert_eq!(
+            GenericArray::<bool, U2>::from_slice(bools),
+            &arr![true, false]
+        );
+    }
+}
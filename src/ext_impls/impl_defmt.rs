@@ -0,0 +1,69 @@
+//! `defmt::Format` implementation
+//!
+//! Arrays are logged like slices, except for byte arrays, which are logged as `{=[u8]:x}`:
+//! the raw bytes without a per-element type tag, rendered as hex by the host decoder.
+
+use defmt::{Format, Formatter};
+
+use crate::{ArrayLength, GenericArray};
+
+impl<T: Format, N: ArrayLength> Format for GenericArray<T, N> {
+    #[inline]
+    fn format(&self, fmt: Formatter<'_>) {
+        if unty::type_equal::<T, u8>() {
+            // SAFETY: `T` is `u8`
+            let bytes: &[u8] =
+                unsafe { core::slice::from_raw_parts(self.as_ptr().cast(), N::USIZE) };
+
+            return defmt::write!(fmt, "{=[u8]:x}", bytes);
+        }
+
+        self.as_slice().format(fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use defmt::export::{fetch_bytes, fetch_string_index, make_formatter};
+    use defmt::Format;
+
+    use crate::typenum::U0;
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn test_bytes() {
+        let [i0, i1] = fetch_string_index().to_le_bytes();
+        arr![0xDEu8, 0xAD, 0xBE, 0xEF].format(make_formatter());
+
+        assert_eq!(
+            fetch_bytes(),
+            [
+                i0, i1, // "{=[u8]:x}"
+                4, 0, 0, 0, // length
+                0xDE, 0xAD, 0xBE, 0xEF, // the raw bytes, without a type tag
+            ]
+        );
+
+        let [i0, i1] = fetch_string_index().to_le_bytes();
+        GenericArray::<u8, U0>::default().format(make_formatter());
+        assert_eq!(fetch_bytes(), [i0, i1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_elements() {
+        let [i0, i1] = fetch_string_index().to_le_bytes();
+        let [j0, j1] = (fetch_string_index() + 1).to_le_bytes();
+        arr![1u16, 0xBEEF].format(make_formatter());
+
+        // encoded exactly like the equivalent slice
+        assert_eq!(
+            fetch_bytes(),
+            [
+                i0, i1, // "{=[?]}"
+                2, 0, 0, 0, // length
+                j0, j1, // "{=u16}"
+                1, 0, 0xEF, 0xBE,
+            ]
+        );
+    }
+}
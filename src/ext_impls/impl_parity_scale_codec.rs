@@ -0,0 +1,143 @@
+//! SCALE codec implementation, including `MaxEncodedLen` and `scale-info` type information
+//!
+//! `GenericArray<T, N>` is encoded exactly like `[T; N]`: the `N` elements back to back,
+//! without a compact length prefix.
+
+use core::mem;
+
+use parity_scale_codec::{
+    ConstEncodedLen, Decode, DecodeWithMemTracking, Encode, EncodeLike, Error, Input,
+    MaxEncodedLen, Output,
+};
+use scale_info::{MetaType, Type, TypeDefArray, TypeInfo};
+
+use crate::{sequence::FallibleGenericSequence as _, ArrayLength, GenericArray};
+
+impl<T: Encode, N: ArrayLength> Encode for GenericArray<T, N> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        mem::size_of::<T>() * N::USIZE
+    }
+
+    #[inline]
+    fn encode_to<W: Output + ?Sized>(&self, dest: &mut W) {
+        for el in self {
+            el.encode_to(dest);
+        }
+    }
+}
+
+impl<T: EncodeLike<U>, U: Encode, N: ArrayLength> EncodeLike<GenericArray<U, N>>
+    for GenericArray<T, N>
+{
+}
+
+impl<T: Decode, N: ArrayLength> Decode for GenericArray<T, N> {
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        GenericArray::try_generate(|_| T::decode(input)).unwrap_or_else(|e| match e {})
+    }
+
+    fn skip<I: Input>(input: &mut I) -> Result<(), Error> {
+        for _ in 0..N::USIZE {
+            T::skip(input)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(T::encoded_fixed_size()? * N::USIZE)
+    }
+}
+
+impl<T: DecodeWithMemTracking, N: ArrayLength> DecodeWithMemTracking for GenericArray<T, N> {}
+
+impl<T: MaxEncodedLen, N: ArrayLength> MaxEncodedLen for GenericArray<T, N> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        T::max_encoded_len().saturating_mul(N::USIZE)
+    }
+}
+
+impl<T: ConstEncodedLen, N: ArrayLength> ConstEncodedLen for GenericArray<T, N> {}
+
+impl<T: TypeInfo + 'static, N: ArrayLength + 'static> TypeInfo for GenericArray<T, N> {
+    type Identity = Self;
+
+    fn type_info() -> Type {
+        TypeDefArray::new(N::U32, MetaType::new::<T>()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parity_scale_codec::{Decode, DecodeAll, Encode, MaxEncodedLen};
+    use scale_info::{meta_type, TypeDef, TypeInfo};
+
+    use crate::typenum::{U0, U3, U4};
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn test_roundtrip() {
+        let array = arr![1u32, 2, 0xDEAD_BEEF];
+        let bytes = array.encode();
+
+        // same encoding as a core array, with no compact length prefix
+        assert_eq!(bytes, [1u32, 2, 0xDEAD_BEEF].encode());
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(array.size_hint(), 12);
+
+        assert_eq!(
+            GenericArray::<u32, U3>::decode(&mut &bytes[..]).unwrap(),
+            array
+        );
+
+        let nested = arr![arr![1u8, 2], arr![3, 4]];
+        assert_eq!(nested.encode(), [1, 2, 3, 4]);
+        assert_eq!(GenericArray::decode(&mut &[1u8, 2, 3, 4][..]), Ok(nested));
+
+        assert!(GenericArray::<u64, U0>::default().encode().is_empty());
+    }
+
+    #[test]
+    fn test_exact_length() {
+        assert!(GenericArray::<u8, U4>::decode(&mut &[1u8, 2, 3][..]).is_err());
+        assert!(GenericArray::<u8, U4>::decode_all(&mut &[1u8, 2, 3, 4, 5][..]).is_err());
+
+        // `skip` consumes exactly the encoded array
+        let mut input = &[1u8, 0, 2, 0, 3, 0, 9][..];
+        GenericArray::<u16, U3>::skip(&mut input).unwrap();
+        assert_eq!(input, [9]);
+
+        assert_eq!(GenericArray::<u16, U3>::encoded_fixed_size(), Some(6));
+    }
+
+    #[test]
+    fn test_max_encoded_len() {
+        assert_eq!(GenericArray::<u32, U3>::max_encoded_len(), 12);
+        assert_eq!(GenericArray::<Option<u8>, U4>::max_encoded_len(), 8);
+        assert_eq!(GenericArray::<u32, U0>::max_encoded_len(), 0);
+    }
+
+    #[test]
+    fn test_type_info() {
+        let info = GenericArray::<u16, U3>::type_info();
+        assert_eq!(info, <[u16; 3]>::type_info());
+
+        match info.type_def {
+            TypeDef::Array(array) => {
+                assert_eq!(array.len, 3);
+                assert_eq!(array.type_param, meta_type::<u16>());
+            }
+            _ => panic!("expected an array type definition"),
+        }
+    }
+
+    #[test]
+    fn test_element_error() {
+        // SCALE only accepts 0 or 1 for `bool`
+        assert!(GenericArray::<bool, U3>::decode(&mut &[1u8, 0, 2][..]).is_err());
+    }
+}
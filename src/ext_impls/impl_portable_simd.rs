@@ -0,0 +1,234 @@
+//! Conversions and chunked views with the nightly `core::simd` module
+//!
+//! The element-wise [`simd_map`](GenericArray::simd_map) and [`simd_zip`](GenericArray::simd_zip)
+//! run whole vectors at a time over the aligned middle of the array, instead of relying on
+//! LLVM to auto-vectorize the scalar `map`/`zip` loops.
+
+use core::simd::{Simd, SimdElement};
+
+use crate::{ArrayLength, Const, GenericArray, IntoArrayLength};
+
+impl<T, N: ArrayLength, const LANES: usize> From<Simd<T, LANES>> for GenericArray<T, N>
+where
+    T: SimdElement,
+    Const<LANES>: IntoArrayLength<ArrayLength = N>,
+{
+    #[inline]
+    fn from(vector: Simd<T, LANES>) -> Self {
+        GenericArray::from_array(vector.to_array())
+    }
+}
+
+impl<T, N: ArrayLength, const LANES: usize> From<GenericArray<T, N>> for Simd<T, LANES>
+where
+    T: SimdElement,
+    Const<LANES>: IntoArrayLength<ArrayLength = N>,
+{
+    #[inline]
+    fn from(array: GenericArray<T, N>) -> Self {
+        Simd::from_array(array.into_array())
+    }
+}
+
+impl<T: SimdElement, N: ArrayLength> GenericArray<T, N> {
+    /// Splits the array into an unaligned prefix, a middle of aligned SIMD vectors,
+    /// and an unaligned suffix, the same as [`slice::as_simd`].
+    ///
+    /// ```rust
+    /// #![feature(portable_simd)]
+    /// # use generic_array::{GenericArray, sequence::GenericSequence, typenum::U100};
+    /// use core::simd::{num::SimdFloat, Simd};
+    ///
+    /// let a = GenericArray::<f32, U100>::generate(|i| i as f32);
+    ///
+    /// let (prefix, middle, suffix) = a.as_simd::<8>();
+    /// let sum = prefix.iter().sum::<f32>()
+    ///     + middle.iter().copied().sum::<Simd<f32, 8>>().reduce_sum()
+    ///     + suffix.iter().sum::<f32>();
+    ///
+    /// assert_eq!(sum, 4950.0);
+    /// ```
+    #[inline]
+    pub fn as_simd<const LANES: usize>(&self) -> (&[T], &[Simd<T, LANES>], &[T]) {
+        self.as_slice().as_simd()
+    }
+
+    /// Splits the array into a mutable unaligned prefix, a middle of aligned SIMD vectors,
+    /// and an unaligned suffix, the same as [`slice::as_simd_mut`].
+    #[inline]
+    pub fn as_simd_mut<const LANES: usize>(
+        &mut self,
+    ) -> (&mut [T], &mut [Simd<T, LANES>], &mut [T]) {
+        self.as_mut_slice().as_simd_mut()
+    }
+
+    /// Maps `f` over the array `LANES` elements at a time.
+    ///
+    /// The aligned middle of the array is mapped in place as whole vectors. The prefix and suffix
+    /// are each padded out to a full vector with copies of their own first element, so `f` never
+    /// sees values that weren't already in the array, and the padding lanes are discarded.
+    ///
+    /// ```rust
+    /// #![feature(portable_simd)]
+    /// # use generic_array::{GenericArray, sequence::GenericSequence, typenum::U1000};
+    /// let a = GenericArray::<f32, U1000>::generate(|i| i as f32);
+    ///
+    /// let b = a.simd_map::<8>(|v| v * v);
+    ///
+    /// assert_eq!(b[999], 998001.0);
+    /// ```
+    #[inline]
+    pub fn simd_map<const LANES: usize>(
+        mut self,
+        f: impl Fn(Simd<T, LANES>) -> Simd<T, LANES>,
+    ) -> Self {
+        let (prefix, middle, suffix) = self.as_simd_mut::<LANES>();
+
+        map_partial(prefix, &f);
+
+        for v in middle {
+            *v = f(*v);
+        }
+
+        map_partial(suffix, &f);
+
+        self
+    }
+
+    /// Combines the array with `rhs` by applying `f` to each pair of vectors of `LANES` elements.
+    ///
+    /// Chunks of `rhs` are loaded unaligned at the same offsets as the aligned chunks of `self`,
+    /// and the ends are padded the same way as [`simd_map`](GenericArray::simd_map).
+    ///
+    /// ```rust
+    /// #![feature(portable_simd)]
+    /// # use generic_array::{GenericArray, sequence::GenericSequence, typenum::U1000};
+    /// let a = GenericArray::<f32, U1000>::generate(|i| i as f32);
+    /// let b = GenericArray::<f32, U1000>::generate(|i| 2.0 * i as f32);
+    ///
+    /// let c = a.simd_zip::<8>(&b, |l, r| l + r);
+    ///
+    /// assert_eq!(c[999], 2997.0);
+    /// ```
+    #[inline]
+    pub fn simd_zip<const LANES: usize>(
+        mut self,
+        rhs: &Self,
+        f: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+    ) -> Self {
+        let (prefix, middle, suffix) = self.as_simd_mut::<LANES>();
+
+        let (rhs_prefix, rest) = rhs.split_at(prefix.len());
+        let (rhs_middle, rhs_suffix) = rest.split_at(middle.len() * LANES);
+
+        zip_partial(prefix, rhs_prefix, &f);
+
+        for (l, r) in middle.iter_mut().zip(rhs_middle.chunks_exact(LANES)) {
+            *l = f(*l, Simd::from_slice(r));
+        }
+
+        zip_partial(suffix, rhs_suffix, &f);
+
+        self
+    }
+}
+
+/// Applies `f` to fewer than `LANES` elements, padded with copies of the first element
+#[inline]
+fn map_partial<T: SimdElement, const LANES: usize>(
+    slice: &mut [T],
+    f: &impl Fn(Simd<T, LANES>) -> Simd<T, LANES>,
+) {
+    if let Some(&first) = slice.first() {
+        let v = f(Simd::load_or(slice, Simd::splat(first)));
+        slice.copy_from_slice(&v.as_array()[..slice.len()]);
+    }
+}
+
+/// Applies `f` to fewer than `LANES` pairs of elements, padded with copies of the first pair
+#[inline]
+fn zip_partial<T: SimdElement, const LANES: usize>(
+    lhs: &mut [T],
+    rhs: &[T],
+    f: &impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+) {
+    if let (Some(&l), Some(&r)) = (lhs.first(), rhs.first()) {
+        let v = f(
+            Simd::load_or(lhs, Simd::splat(l)),
+            Simd::load_or(rhs, Simd::splat(r)),
+        );
+        lhs.copy_from_slice(&v.as_array()[..lhs.len()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::simd::{u8x4, Simd};
+
+    use crate::functional::FunctionalSequence;
+    use crate::sequence::GenericSequence;
+    use crate::typenum::{U0, U1022, U1023, U3, U4};
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn vector_conversions() {
+        let a: GenericArray<u8, U4> = u8x4::from_array([1, 2, 3, 4]).into();
+        assert_eq!(a, arr![1, 2, 3, 4]);
+
+        let v: u8x4 = a.into();
+        assert_eq!(v, u8x4::from_array([1, 2, 3, 4]));
+
+        let v: Simd<f32, 3> = arr![1.0, 2.0, 3.0].into();
+        assert_eq!(GenericArray::<f32, U3>::from(v), arr![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn views() {
+        let mut a = GenericArray::<u32, U1023>::generate(|i| i as u32);
+
+        let (prefix, middle, suffix) = a.as_simd::<8>();
+        assert_eq!(prefix.len() + middle.len() * 8 + suffix.len(), 1023);
+        assert!(prefix.len() < 8 && suffix.len() < 8);
+
+        let (prefix, middle, suffix) = a.as_simd_mut::<8>();
+        prefix.fill(0);
+        middle.fill(Simd::splat(0));
+        suffix.fill(0);
+        assert!(a.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn map() {
+        let a = GenericArray::<u32, U1023>::generate(|i| i as u32 + 1);
+
+        assert_eq!(a.simd_map::<8>(|v| v * v), a.map(|x| x * x));
+
+        // padding lanes must not be fed values that `f` can't handle
+        assert_eq!(
+            a.simd_map::<16>(|v| Simd::splat(1000) / v),
+            a.map(|x| 1000 / x)
+        );
+
+        assert_eq!(arr![1u8, 2, 3].simd_map::<64>(|v| v + v), arr![2, 4, 6]);
+        assert_eq!(
+            GenericArray::<u8, U0>::default().simd_map::<4>(|v| v),
+            GenericArray::<u8, U0>::default()
+        );
+    }
+
+    #[test]
+    fn zip() {
+        let a = GenericArray::<u32, U1023>::generate(|i| i as u32);
+        let b = GenericArray::<u32, U1023>::generate(|i| 2 * i as u32 + 1);
+
+        assert_eq!(a.simd_zip::<8>(&b, |l, r| l + r), a.zip(b, |l, r| l + r));
+        assert_eq!(a.simd_zip::<4>(&b, |l, r| l / r), a.zip(b, |l, r| l / r));
+
+        // `rhs` keeps the same element offsets even when `self` isn't vector-aligned
+        let (_, rest) = a.split_at(1);
+        let a: &GenericArray<u32, U1022> = rest.try_into().unwrap();
+        let (_, rest) = b.split_at(1);
+        let b: &GenericArray<u32, U1022> = rest.try_into().unwrap();
+        assert_eq!(a.simd_zip::<8>(b, |l, r| r - l), a.zip(*b, |l, r| r - l));
+    }
+}
@@ -0,0 +1,65 @@
+//! postcard `MaxSize` implementation
+//!
+//! The serde implementation serializes `GenericArray<T, N>` as a tuple, which postcard
+//! encodes exactly like `[T; N]`, so this mirrors postcard's impl for `[T; N]`.
+//!
+//! postcard's experimental `Schema` is not implemented. Its schema for `[T; N]` is a
+//! `&'static` slice of `N` element schemas, and for a generic `GenericArray` that slice
+//! can't be borrowed as `'static` on stable Rust (E0492), since the compiler can't prove
+//! `N::ArrayType` has no interior mutability.
+
+impl<T, N: crate::ArrayLength> postcard::experimental::max_size::MaxSize
+    for crate::GenericArray<T, N>
+where
+    T: postcard::experimental::max_size::MaxSize,
+{
+    const POSTCARD_MAX_SIZE: usize = T::POSTCARD_MAX_SIZE * N::USIZE;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_max_size() {
+        use postcard::experimental::max_size::MaxSize;
+
+        use crate::typenum::{U0, U16, U3};
+        use crate::GenericArray;
+
+        assert_eq!(GenericArray::<u8, U16>::POSTCARD_MAX_SIZE, 16);
+        assert_eq!(
+            GenericArray::<u32, U3>::POSTCARD_MAX_SIZE,
+            <[u32; 3]>::POSTCARD_MAX_SIZE
+        );
+        assert_eq!(
+            GenericArray::<GenericArray<u16, U3>, U3>::POSTCARD_MAX_SIZE,
+            <[[u16; 3]; 3]>::POSTCARD_MAX_SIZE
+        );
+        assert_eq!(GenericArray::<u64, U0>::POSTCARD_MAX_SIZE, 0);
+
+        // usable to size buffers at compile time
+        let _buf = [0u8; GenericArray::<u32, U3>::POSTCARD_MAX_SIZE];
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialized_size() {
+        use postcard::experimental::max_size::MaxSize;
+
+        use crate::arr;
+        use crate::typenum::U3;
+        use crate::GenericArray;
+
+        let array = arr![u32::MAX, 1, 300];
+
+        let mut buf = [0u8; GenericArray::<u32, U3>::POSTCARD_MAX_SIZE];
+        let len = postcard::to_slice(&array, &mut buf).unwrap().len();
+        assert_eq!(len, GenericArray::<u32, U3>::POSTCARD_MAX_SIZE - 4 - 3);
+
+        let mut core_buf = [0u8; 15];
+        let core = postcard::to_slice(&[u32::MAX, 1, 300], &mut core_buf).unwrap();
+        assert_eq!(&buf[..len], &core[..]);
+
+        let decoded: GenericArray<u32, U3> = postcard::from_bytes(&buf[..len]).unwrap();
+        assert_eq!(decoded, array);
+    }
+}
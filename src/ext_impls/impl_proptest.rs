@@ -0,0 +1,263 @@
+//! `proptest` strategies and `Arbitrary` implementation
+//!
+//! These mirror proptest's own `[T; N]` support in `proptest::array`.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::strategy::{NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+use crate::sequence::{FallibleGenericSequence as _, GenericSequence as _};
+use crate::{ArrayLength, GenericArray};
+
+/// Strategy to generate `GenericArray<S::Value, N>`, with every element generated by the
+/// same strategy `S`.
+///
+/// Created by [`uniform`].
+#[must_use = "strategies do nothing unless used"]
+#[derive(Clone, Copy)]
+pub struct UniformArrayStrategy<S, N> {
+    strategy: S,
+    _marker: PhantomData<N>,
+}
+
+impl<S, N> UniformArrayStrategy<S, N> {
+    /// Creates a strategy generating every element with `strategy`.
+    ///
+    /// Prefer [`uniform`] unless something precludes its use.
+    #[inline]
+    pub const fn new(strategy: S) -> Self {
+        UniformArrayStrategy {
+            strategy,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Creates a strategy to generate `GenericArray<S::Value, N>`, with every element
+/// generated by `strategy`.
+///
+/// Shrinking simplifies one element at a time, from first to last.
+///
+/// ```rust
+/// use generic_array::{proptest::uniform, typenum::U4};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(bytes in uniform::<U4, _>(0..10u8))| {
+///     prop_assert!(bytes.iter().all(|&b| b < 10));
+/// });
+/// ```
+#[inline]
+pub const fn uniform<N: ArrayLength, S: Strategy>(strategy: S) -> UniformArrayStrategy<S, N> {
+    UniformArrayStrategy::new(strategy)
+}
+
+/// `ValueTree` of a `GenericArray`, which shrinks one element at a time.
+pub struct ArrayValueTree<T, N: ArrayLength> {
+    tree: GenericArray<T, N>,
+    shrinker: usize,
+    last_shrinker: Option<usize>,
+}
+
+impl<T, N: ArrayLength> ArrayValueTree<T, N> {
+    #[inline]
+    fn new(tree: GenericArray<T, N>) -> Self {
+        ArrayValueTree {
+            tree,
+            shrinker: 0,
+            last_shrinker: None,
+        }
+    }
+}
+
+impl<S: fmt::Debug, N: ArrayLength> fmt::Debug for UniformArrayStrategy<S, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniformArrayStrategy")
+            .field("strategy", &self.strategy)
+            .field("len", &N::USIZE)
+            .finish()
+    }
+}
+
+impl<T: Clone, N: ArrayLength> Clone for ArrayValueTree<T, N> {
+    fn clone(&self) -> Self {
+        ArrayValueTree {
+            tree: self.tree.clone(),
+            shrinker: self.shrinker,
+            last_shrinker: self.last_shrinker,
+        }
+    }
+}
+
+impl<T: fmt::Debug, N: ArrayLength> fmt::Debug for ArrayValueTree<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayValueTree")
+            .field("tree", &self.tree)
+            .field("shrinker", &self.shrinker)
+            .field("last_shrinker", &self.last_shrinker)
+            .finish()
+    }
+}
+
+impl<S: Strategy, N: ArrayLength> Strategy for UniformArrayStrategy<S, N> {
+    type Tree = ArrayValueTree<S::Tree, N>;
+    type Value = GenericArray<S::Value, N>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        GenericArray::try_generate(|_| self.strategy.new_tree(runner))
+            .unwrap_or_else(|e| match e {})
+            .map(ArrayValueTree::new)
+    }
+}
+
+// a `GenericArray` of strategies is a strategy for a `GenericArray` of values, like `[S; N]`
+impl<S: Strategy, N: ArrayLength> Strategy for GenericArray<S, N> {
+    type Tree = ArrayValueTree<S::Tree, N>;
+    type Value = GenericArray<S::Value, N>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        GenericArray::try_generate(|i| self[i].new_tree(runner))
+            .unwrap_or_else(|e| match e {})
+            .map(ArrayValueTree::new)
+    }
+}
+
+impl<T: ValueTree, N: ArrayLength> ValueTree for ArrayValueTree<T, N> {
+    type Value = GenericArray<T::Value, N>;
+
+    fn current(&self) -> Self::Value {
+        GenericArray::generate(|i| self.tree[i].current())
+    }
+
+    fn simplify(&mut self) -> bool {
+        while self.shrinker < N::USIZE {
+            if self.tree[self.shrinker].simplify() {
+                self.last_shrinker = Some(self.shrinker);
+                return true;
+            }
+
+            self.shrinker += 1;
+        }
+
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.last_shrinker {
+            Some(shrinker) => {
+                self.shrinker = shrinker;
+
+                if self.tree[shrinker].complicate() {
+                    true
+                } else {
+                    self.last_shrinker = None;
+                    false
+                }
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T: Arbitrary, N: ArrayLength> Arbitrary for GenericArray<T, N> {
+    type Parameters = T::Parameters;
+    type Strategy = UniformArrayStrategy<T::Strategy, N>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        uniform(any_with::<T>(args))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::arbitrary::any;
+    use proptest::strategy::{Strategy, ValueTree};
+    use proptest::test_runner::TestRunner;
+
+    use super::uniform;
+    use crate::typenum::{U0, U2, U4};
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn new_tree_and_current() {
+        let mut runner = TestRunner::deterministic();
+
+        let tree = uniform::<U4, _>(10..20u8).new_tree(&mut runner).unwrap();
+        let value = tree.current();
+        assert_eq!(value.len(), 4);
+        assert!(value.iter().all(|x| (10..20).contains(x)));
+
+        let tree = any::<GenericArray<u16, U2>>()
+            .new_tree(&mut runner)
+            .unwrap();
+        assert_eq!(tree.current().len(), 2);
+
+        // zero-length array: nothing to simplify or complicate
+        let mut tree = uniform::<U0, _>(any::<u8>()).new_tree(&mut runner).unwrap();
+        assert_eq!(tree.current(), GenericArray::<u8, U0>::default());
+        assert!(!tree.simplify());
+        assert!(!tree.complicate());
+    }
+
+    #[test]
+    fn shrinks_element_by_element() {
+        let mut runner = TestRunner::deterministic();
+
+        // strategies per element, like `[S; N]`
+        let mut tree = arr![5..6u8, 7..8u8].new_tree(&mut runner).unwrap();
+        assert_eq!(tree.current(), arr![5, 7]);
+        assert!(!tree.simplify());
+
+        let mut tree = uniform::<U2, _>(0..100u32).new_tree(&mut runner).unwrap();
+
+        // simplify fully, the first element reaches its minimum before the second moves
+        let second = tree.current()[1];
+        while tree.simplify() {
+            if tree.current()[0] != 0 {
+                assert_eq!(tree.current()[1], second);
+            }
+        }
+        assert_eq!(tree.current(), arr![0, 0]);
+    }
+
+    #[test]
+    fn shrinks_fully_ltr() {
+        fn pass(a: GenericArray<i32, U2>) -> bool {
+            a[0] * a[1] <= 9
+        }
+
+        let input = uniform::<U2, _>(0..32);
+        let mut runner = TestRunner::deterministic();
+
+        let mut cases_tested = 0;
+        for _ in 0..256 {
+            // find a failing test case
+            let mut case = input.new_tree(&mut runner).unwrap();
+            if pass(case.current()) {
+                continue;
+            }
+
+            loop {
+                if pass(case.current()) {
+                    if !case.complicate() {
+                        break;
+                    }
+                } else if !case.simplify() {
+                    break;
+                }
+            }
+
+            let last = case.current();
+            assert!(!pass(last));
+            // maximally shrunken
+            assert!(pass(arr![last[0] - 1, last[1]]));
+            assert!(pass(arr![last[0], last[1] - 1]));
+
+            cases_tested += 1;
+        }
+
+        assert!(cases_tested > 32, "didn't find enough test cases");
+    }
+}
@@ -0,0 +1,70 @@
+use alloc::boxed::Box;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{sequence::GenericSequence as _, ArrayLength, GenericArray};
+
+impl<T: Arbitrary, N: ArrayLength> Arbitrary for GenericArray<T, N> {
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        GenericArray::generate(|_| T::arbitrary(g))
+    }
+
+    /// Shrinks one element at a time, from first to last, keeping the others unchanged.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let array = self.clone();
+
+        Box::new((0..N::USIZE).flat_map(move |i| {
+            let array = array.clone();
+
+            array[i].shrink().map(move |el| {
+                let mut array = array.clone();
+                array[i] = el;
+                array
+            })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use quickcheck::{Arbitrary, Gen};
+
+    use crate::typenum::{U0, U2, U4};
+    use crate::{arr, GenericArray};
+
+    #[test]
+    fn arbitrary() {
+        let mut g = Gen::new(10);
+
+        let a = GenericArray::<u8, U4>::arbitrary(&mut g);
+        assert_eq!(a.len(), 4);
+
+        let z = GenericArray::<u8, U0>::arbitrary(&mut g);
+        assert_eq!(z.len(), 0);
+    }
+
+    #[test]
+    fn shrink() {
+        // each shrunk array differs from the original in exactly one element
+        let array = arr![3u8, 4];
+        let shrunk: Vec<_> = array.shrink().collect();
+
+        let expected: Vec<_> = 3u8
+            .shrink()
+            .map(|x| arr![x, 4])
+            .chain(4u8.shrink().map(|x| arr![3, x]))
+            .collect();
+        assert_eq!(shrunk, expected);
+
+        // elements shrink in order, first to last
+        assert_eq!(shrunk.first().map(|a| a[1]), Some(4));
+        assert_eq!(shrunk.last().map(|a| a[0]), Some(3));
+
+        // nothing left to shrink
+        assert_eq!(GenericArray::<u8, U2>::default().shrink().count(), 0);
+        assert_eq!(GenericArray::<u8, U0>::default().shrink().count(), 0);
+    }
+}
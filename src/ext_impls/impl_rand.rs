@@ -0,0 +1,83 @@
+//! Random generation of arrays with `rand`
+//!
+//! These mirror rand's own impls for `[T; N]`.
+
+use rand::distr::{Distribution, StandardUniform};
+use rand::{Fill, Rng};
+
+use crate::{sequence::GenericSequence as _, ArrayLength, GenericArray};
+
+impl<T, N: ArrayLength> Distribution<GenericArray<T, N>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GenericArray<T, N> {
+        GenericArray::generate(|_| rng.random())
+    }
+}
+
+impl<T, N: ArrayLength> Fill for GenericArray<T, N>
+where
+    [T]: Fill,
+{
+    #[inline]
+    fn fill<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        <[T] as Fill>::fill(self, rng)
+    }
+}
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Creates a new array with each element sampled from the
+    /// [`StandardUniform`] distribution, like `rng.random::<[T; N]>()`.
+    ///
+    /// ```rust
+    /// # use generic_array::{GenericArray, typenum::U16};
+    /// let key = GenericArray::<u8, U16>::random(&mut rand::rng());
+    /// ```
+    #[inline]
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self
+    where
+        StandardUniform: Distribution<T>,
+    {
+        Distribution::<Self>::sample(&StandardUniform, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::typenum::{U0, U3, U32};
+    use crate::GenericArray;
+
+    #[test]
+    fn test_same_as_core_array() {
+        let a: GenericArray<u32, U3> = StdRng::seed_from_u64(7).random();
+        let b: [u32; 3] = StdRng::seed_from_u64(7).random();
+        assert_eq!(a.as_slice(), b);
+
+        let a = GenericArray::<(u8, bool), U3>::random(&mut StdRng::seed_from_u64(7));
+        let b: [(u8, bool); 3] = StdRng::seed_from_u64(7).random();
+        assert_eq!(a.as_slice(), b);
+
+        let _: GenericArray<u8, U0> = StdRng::seed_from_u64(7).random();
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut a = GenericArray::<u8, U32>::default();
+        let mut b = [0u8; 32];
+        StdRng::seed_from_u64(7).fill(&mut a);
+        StdRng::seed_from_u64(7).fill(&mut b);
+        assert_eq!(a.as_slice(), b);
+        assert_ne!(a, GenericArray::<u8, U32>::default());
+
+        let mut a = GenericArray::<i64, U3>::default();
+        let mut b = [0i64; 3];
+        StdRng::seed_from_u64(7).fill(&mut a);
+        StdRng::seed_from_u64(7).fill(&mut b);
+        assert_eq!(a.as_slice(), b);
+    }
+}
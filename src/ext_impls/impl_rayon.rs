@@ -0,0 +1,291 @@
+//! Parallel iteration over arrays with `rayon`, and parallel construction of boxed arrays
+//!
+//! The boxed constructors write each element directly into its final place on the heap,
+//! so even very large arrays never pass through the stack.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::mem;
+use core::ptr;
+
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
+
+use super::impl_alloc::IntrusiveBoxedArrayBuilder;
+use crate::{ArrayLength, GenericArray};
+
+/// Moves the elements to the heap first, since rayon's producers need to split ownership.
+impl<T: Send, N: ArrayLength> IntoParallelIterator for GenericArray<T, N> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        Vec::from(self).into_par_iter()
+    }
+}
+
+impl<T: Send, N: ArrayLength> IntoParallelIterator for Box<GenericArray<T, N>> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        GenericArray::into_vec(self).into_par_iter()
+    }
+}
+
+impl<'a, T: Sync, N: ArrayLength> IntoParallelIterator for &'a GenericArray<T, N> {
+    type Iter = rayon::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T: Send, N: ArrayLength> IntoParallelIterator for &'a mut GenericArray<T, N> {
+    type Iter = rayon::slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice().into_par_iter()
+    }
+}
+
+impl<T, N: ArrayLength> GenericArray<T, N> {
+    /// Like [`GenericSequence::generate`](crate::sequence::GenericSequence::generate) for
+    /// `Box<GenericArray<T, N>>`, but calls `f` in parallel on the rayon thread pool.
+    ///
+    /// Each element is written in place into the heap allocation.
+    ///
+    /// ```rust
+    /// # use generic_array::{GenericArray, typenum::{Exp, U10, U6}};
+    /// # #[cfg(not(miri))] {
+    /// let squares = GenericArray::<u64, Exp<U10, U6>>::par_generate(|i| (i * i) as u64);
+    ///
+    /// assert_eq!(squares[999_999], 999_998_000_001);
+    /// # }
+    /// ```
+    pub fn par_generate<F>(f: F) -> Box<GenericArray<T, N>>
+    where
+        T: Send,
+        F: Fn(usize) -> T + Sync + Send,
+    {
+        par_collect_boxed((0..N::USIZE).into_par_iter().map(f))
+    }
+
+    /// Like [`FunctionalSequence::map`](crate::functional::FunctionalSequence::map) for
+    /// `Box<GenericArray<T, N>>`, but calls `f` in parallel on the rayon thread pool.
+    ///
+    /// A new heap allocation is filled in place, and the elements of `self` are moved out
+    /// without copying the array.
+    ///
+    /// ```rust
+    /// # use generic_array::{box_arr, GenericArray, typenum::{Exp, U10, U6}};
+    /// # #[cfg(not(miri))] {
+    /// let a = box_arr![1u32; Exp<U10, U6>];
+    ///
+    /// let b = a.par_map(|x| x as u64 * 3);
+    ///
+    /// assert!(b.iter().all(|&x| x == 3));
+    /// # }
+    /// ```
+    pub fn par_map<U, F>(self: Box<GenericArray<T, N>>, f: F) -> Box<GenericArray<U, N>>
+    where
+        T: Send,
+        U: Send,
+        F: Fn(T) -> U + Sync + Send,
+    {
+        par_collect_boxed(self.into_par_iter().map(f))
+    }
+}
+
+/// Fills a new boxed array from an indexed parallel iterator of exactly `N` items
+fn par_collect_boxed<T, N, I>(iter: I) -> Box<GenericArray<T, N>>
+where
+    T: Send,
+    N: ArrayLength,
+    I: IndexedParallelIterator<Item = T>,
+{
+    assert_eq!(iter.len(), N::USIZE);
+
+    let mut builder = IntrusiveBoxedArrayBuilder::<T, N>::new();
+
+    unsafe {
+        let (slice, position) = builder.slice_position();
+
+        // Each split of the work tracks the contiguous run of elements it has written,
+        // and adjacent runs are merged in order. If `f` panics, every run that isn't merged
+        // into the final one is dropped during unwinding, along with its elements.
+        let written = slice
+            .par_iter_mut()
+            .zip(iter)
+            .fold(
+                || None::<Written<T>>,
+                |written, (dst, value)| {
+                    let dst = dst.write(value) as *mut T;
+
+                    Some(match written {
+                        Some(mut written) => {
+                            debug_assert!(written.start.add(written.len) == dst);
+                            written.len += 1;
+                            written
+                        }
+                        None => Written { start: dst, len: 1 },
+                    })
+                },
+            )
+            .reduce_with(|left, right| match (left, right) {
+                (Some(mut left), Some(right)) if left.start.add(left.len) == right.start => {
+                    left.len += right.into_len();
+                    Some(left)
+                }
+                (left, None) => left,
+                (None, right) => right,
+                // never happens with rayon's in-order reduction, but `right` is dropped
+                // here regardless and the length check below catches it
+                (left, Some(_)) => left,
+            })
+            .flatten();
+
+        let len = written.map_or(0, Written::into_len);
+
+        // the builder drops the written elements on failure from here on
+        *position = len;
+
+        assert_eq!(len, N::USIZE, "parallel iterator produced too few items");
+
+        builder.finish()
+    }
+}
+
+/// A contiguous run of initialized elements, which are dropped along with it
+struct Written<T> {
+    start: *mut T,
+    len: usize,
+}
+
+// SAFETY: this only stands in for the `T`s it owns
+unsafe impl<T: Send> Send for Written<T> {}
+
+impl<T> Written<T> {
+    /// Releases the elements without dropping them, returning how many there were
+    #[inline]
+    fn into_len(self) -> usize {
+        let len = self.len;
+        mem::forget(self);
+        len
+    }
+}
+
+impl<T> Drop for Written<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.start, self.len));
+        }
+    }
+}
+
+// crossbeam-epoch, used by rayon's thread pool, trips Stacked Borrows and leaves threads
+// running, so these are ignored under Miri by default. To check the unsafe code here, run:
+//   MIRIFLAGS="-Zmiri-tree-borrows -Zmiri-ignore-leaks" cargo +nightly miri test --features rayon --lib impl_rayon -- --ignored
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use rayon::prelude::*;
+
+    use crate::sequence::GenericSequence;
+    use crate::typenum::{U0, U1000, U4, U5};
+    use crate::{arr, box_arr, GenericArray};
+
+    // kept small enough to run under Miri
+    type Big = U1000;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn iterators() {
+        let mut a = GenericArray::<u64, Big>::default_boxed();
+
+        a.par_iter_mut()
+            .enumerate()
+            .for_each(|(i, x)| *x = i as u64);
+        assert_eq!(a.par_iter().sum::<u64>(), 999 * 1000 / 2);
+        assert_eq!((&*a).into_par_iter().max(), Some(&999));
+
+        let v: alloc::vec::Vec<u64> = a.into_par_iter().filter(|x| x % 2 == 0).collect();
+        assert_eq!(v.len(), 500);
+
+        let v: alloc::vec::Vec<u8> = arr![1u8, 2, 3, 4].into_par_iter().rev().collect();
+        assert_eq!(v, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn generate() {
+        let a = GenericArray::<usize, Big>::par_generate(|i| i * 2);
+        assert_eq!(*a, *Box::<GenericArray<usize, Big>>::generate(|i| i * 2));
+
+        let empty = GenericArray::<Box<u8>, U0>::par_generate(|_| unreachable!());
+        assert!(empty.is_empty());
+
+        let zst = GenericArray::<(), Big>::par_generate(|_| ());
+        assert_eq!(zst.len(), 1000);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn map() {
+        let a = box_arr![7u16; Big];
+        let b = a.par_map(|x| x as u32 + 1);
+        assert!(b.par_iter().all(|&x| x == 8));
+
+        let boxes = GenericArray::<Box<u32>, U4>::par_generate(|i| Box::new(i as u32));
+        let c = boxes.par_map(|b| *b * 10);
+        assert_eq!(*c, arr![0, 10, 20, 30]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn panic_drops_written_elements() {
+        extern crate std;
+
+        static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted;
+
+        impl Counted {
+            fn new() -> Self {
+                LIVE.fetch_add(1, Ordering::SeqCst);
+                Counted
+            }
+        }
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                LIVE.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+
+        let result = std::panic::catch_unwind(|| {
+            GenericArray::<Counted, Big>::par_generate(|i| {
+                if i == 500 {
+                    panic!("oops");
+                }
+                Counted::new()
+            })
+        });
+
+        assert!(result.is_err());
+        assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+
+        let a = GenericArray::<Counted, U5>::par_generate(|_| Counted::new());
+        assert_eq!(LIVE.load(Ordering::SeqCst), 5);
+        drop(a);
+        assert_eq!(LIVE.load(Ordering::SeqCst), 0);
+    }
+}
@@ -5,7 +5,7 @@
 // `Resolver`s (often `()`) and `Copy` element types this is a non-issue; staying in sync
 // with upstream is preferable to diverging here.
 
-use core::mem;
+use core::{mem, ptr};
 
 use rkyv_0_8::{
     rancor::Fallible,
@@ -13,7 +13,8 @@ use rkyv_0_8::{
     Archive, Deserialize, Place, Portable, Serialize,
 };
 
-use crate::{ArrayLength, GenericArray};
+use crate::string::ArchivedGenericString;
+use crate::{ArrayLength, GenericArray, GenericString};
 
 // SAFETY: `GenericArray<T, N>` is a `T` array and so is portable as long as `T` is also
 // `Portable`.
@@ -98,6 +99,53 @@ where
     }
 }
 
+// SAFETY: `ArchivedGenericString` is `repr(C)` and only contains portable fields.
+unsafe impl<N: ArrayLength> Portable for ArchivedGenericString<N> {}
+
+impl<N: ArrayLength> Archive for GenericString<N> {
+    type Archived = ArchivedGenericString<N>;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: both places point to fields of `out`, and only fully-initialized values are
+        // written to them. Any padding between them was already zeroed by the serializer.
+        unsafe {
+            let base = out.ptr();
+
+            let buf = Place::from_field_unchecked(out, ptr::addr_of_mut!((*base).buf));
+            buf.write(self.buf.clone());
+
+            let len = Place::from_field_unchecked(out, ptr::addr_of_mut!((*base).len));
+            self.len.resolve((), len);
+        }
+    }
+}
+
+impl<S, N: ArrayLength> Serialize<S> for GenericString<N>
+where
+    S: Fallible + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D, N: ArrayLength> Deserialize<GenericString<N>, D> for ArchivedGenericString<N>
+where
+    D: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<GenericString<N>, D::Error> {
+        // archived strings uphold the same invariants as `GenericString`
+        Ok(GenericString {
+            buf: self.buf.clone(),
+            len: self.len(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rkyv_0_8 as rkyv;
@@ -150,6 +198,22 @@ mod tests {
             rkyv::deserialize::<GenericArray<String, U3>, Error>(archived).unwrap();
         assert_eq!(deserialized, array);
     }
+
+    #[test]
+    fn test_rkyv_roundtrip_generic_string() {
+        use crate::GenericString;
+
+        let string: GenericString<U32> = "hello rkyv".parse().unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&string).unwrap();
+        let archived =
+            unsafe { rkyv::access_unchecked::<rkyv::Archived<GenericString<U32>>>(&bytes) };
+        assert_eq!(archived.as_str(), "hello rkyv");
+        assert_eq!(*archived, string);
+
+        let deserialized: GenericString<U32> =
+            rkyv::deserialize::<GenericString<U32>, Error>(archived).unwrap();
+        assert_eq!(deserialized, string);
+    }
 }
 
 #[cfg(all(test, feature = "bytecheck-0_8"))]
@@ -177,4 +241,30 @@ mod tests_full {
         let result = rkyv::access::<rkyv::Archived<GenericArray<u32, U6>>, Error>(truncated);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_check_bytes_generic_string() {
+        use crate::string::ArchivedGenericString;
+        use crate::typenum::U8;
+        use crate::GenericString;
+
+        let string: GenericString<U8> = "héllo".parse().unwrap();
+        let mut bytes = rkyv::to_bytes::<Error>(&string).unwrap();
+        let archived = rkyv::access::<ArchivedGenericString<U8>, Error>(&bytes).unwrap();
+        assert_eq!(archived.as_str(), "héllo");
+
+        // split 'é' in half
+        bytes[2] = b'e';
+        assert!(rkyv::access::<ArchivedGenericString<U8>, Error>(&bytes).is_err());
+        bytes[2] = 0xA9;
+
+        // garbage after the end of the string
+        bytes[7] = 1;
+        assert!(rkyv::access::<ArchivedGenericString<U8>, Error>(&bytes).is_err());
+        bytes[7] = 0;
+
+        // length greater than capacity
+        bytes[8] = 9;
+        assert!(rkyv::access::<ArchivedGenericString<U8>, Error>(&bytes).is_err());
+    }
 }
@@ -0,0 +1,106 @@
+//! `schemars` `JsonSchema` implementation
+//!
+//! `GenericArray<T, N>` gets the same schema as `[T; N]`, an array of exactly `N` items.
+
+use alloc::borrow::Cow;
+use alloc::format;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{ArrayLength, GenericArray};
+
+impl<T: JsonSchema, N: ArrayLength> JsonSchema for GenericArray<T, N> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        match N::USIZE {
+            0 => "EmptyArray".into(),
+            len => format!("Array_size_{}_of_{}", len, T::schema_name()).into(),
+        }
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        match N::USIZE {
+            0 => "[]".into(),
+            len => format!("[{}; {}]", len, T::schema_id()).into(),
+        }
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        if N::USIZE == 0 {
+            return json_schema!({
+                "type": "array",
+                "maxItems": 0,
+            });
+        }
+
+        json_schema!({
+            "type": "array",
+            "items": generator.subschema_for::<T>(),
+            "minItems": N::USIZE,
+            "maxItems": N::USIZE,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::{json_schema, schema_for, JsonSchema};
+
+    use crate::typenum::{U0, U2, U3};
+    use crate::GenericArray;
+
+    #[test]
+    fn test_schema() {
+        let schema = schema_for!(GenericArray<u16, U3>);
+        assert_eq!(schema, schema_for!([u16; 3]));
+
+        assert_eq!(schema.get("minItems"), Some(&3.into()));
+        assert_eq!(schema.get("maxItems"), Some(&3.into()));
+        assert_eq!(
+            schema.get("items"),
+            Some(&json_schema!({ "type": "integer", "format": "uint16", "minimum": 0, "maximum": 65535 }).into())
+        );
+
+        assert_eq!(
+            schema_for!(GenericArray<GenericArray<bool, U2>, U3>),
+            schema_for!([[bool; 2]; 3])
+        );
+        assert_eq!(schema_for!(GenericArray<u8, U0>), schema_for!([u8; 0]));
+
+        assert_eq!(
+            GenericArray::<u16, U3>::schema_id(),
+            <[u16; 3]>::schema_id()
+        );
+        assert_eq!(
+            GenericArray::<u16, U3>::schema_name(),
+            <[u16; 3]>::schema_name()
+        );
+    }
+
+    #[derive(Debug)]
+    struct Key;
+
+    impl JsonSchema for Key {
+        fn schema_name() -> alloc::borrow::Cow<'static, str> {
+            "Key".into()
+        }
+
+        fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+            json_schema!({ "type": "string" })
+        }
+    }
+
+    #[test]
+    fn test_referenced_items() {
+        // non-inline element schemas are referenced from `items`, as for core arrays
+        let schema = schema_for!(GenericArray<Key, U2>);
+        assert_eq!(schema, schema_for!([Key; 2]));
+        assert_eq!(
+            schema.get("items"),
+            Some(&json_schema!({ "$ref": "#/$defs/Key" }).into())
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! Serde serialization/deserialization implementation
 
-use crate::{ArrayLength, GenericArray, IntrusiveArrayBuilder};
+use crate::{ArrayLength, GenericArray, GenericString, IntrusiveArrayBuilder};
 use core::fmt;
 use core::marker::PhantomData;
 
@@ -107,6 +107,44 @@ where
     }
 }
 
+impl<N: ArrayLength> Serialize for GenericString<N> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+struct GSVisitor<N> {
+    _n: PhantomData<N>,
+}
+
+impl<'de, N: ArrayLength> Visitor<'de> for GSVisitor<N> {
+    type Value = GenericString<N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a string of at most {} bytes", N::USIZE)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<GenericString<N>, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(|_| E::invalid_length(v.len(), &self))
+    }
+}
+
+impl<'de, N: ArrayLength> Deserialize<'de> for GenericString<N> {
+    fn deserialize<D>(deserializer: D) -> Result<GenericString<N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(GSVisitor { _n: PhantomData })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,4 +204,32 @@ mod tests {
         let result = GenericArray::<u8, typenum::U4>::deserialize(de);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_string_roundtrip() {
+        let s: GenericString<typenum::U8> = "héllo".parse().unwrap();
+
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(json, r#""héllo""#);
+        assert_eq!(
+            serde_json::from_str::<GenericString<typenum::U8>>(&json).unwrap(),
+            s
+        );
+
+        // same encoding as `str`
+        let serialized = bincode::serialize(&s).unwrap();
+        assert_eq!(serialized, bincode::serialize("héllo").unwrap());
+        assert_eq!(
+            bincode::deserialize::<GenericString<typenum::U8>>(&serialized).unwrap(),
+            s
+        );
+    }
+
+    #[test]
+    fn test_string_too_long() {
+        let result = serde_json::from_str::<GenericString<typenum::U4>>(r#""hello""#);
+        assert!(result.is_err());
+
+        assert!(serde_json::from_str::<GenericString<typenum::U5>>(r#""hello""#).is_ok());
+    }
 }
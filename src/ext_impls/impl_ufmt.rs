@@ -0,0 +1,117 @@
+//! `ufmt` formatting implementations
+//!
+//! `uDebug` formats arrays like slices, and `uDisplay` formats byte arrays as lowercase hex,
+//! the same as `{:x}` with `core::fmt`.
+
+use core::str;
+
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::hex::hex_encode;
+use crate::{ArrayLength, GenericArray};
+
+impl<T: uDebug, N: ArrayLength> uDebug for GenericArray<T, N> {
+    #[inline]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <[T] as uDebug>::fmt(self, f)
+    }
+}
+
+impl<N: ArrayLength> uDisplay for GenericArray<u8, N> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        // small fixed buffer, as these are mostly used on targets with little stack to spare
+        let mut buf = [0u8; 64];
+
+        for chunk in self.chunks(buf.len() / 2) {
+            let digits = &mut buf[..chunk.len() * 2];
+            hex_encode::<false>(chunk, digits);
+
+            // SAFETY: hex digits are ASCII
+            f.write_str(unsafe { str::from_utf8_unchecked(digits) })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::Infallible;
+    use core::fmt::{self, Write as _};
+
+    use ufmt::{uWrite, uwrite};
+
+    use crate::typenum::{U0, U100};
+    use crate::{arr, GenericArray};
+
+    /// Fixed-capacity string for the tests, as `alloc` may be disabled
+    struct Buf {
+        bytes: [u8; 256],
+        len: usize,
+    }
+
+    impl Buf {
+        fn new() -> Self {
+            Buf {
+                bytes: [0; 256],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl uWrite for Buf {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.bytes[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    impl fmt::Write for Buf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            uWrite::write_str(self, s).map_err(|e| match e {})
+        }
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut s = Buf::new();
+        uwrite!(s, "{:?}", arr![1u16, 2, 3]).unwrap();
+        assert_eq!(s.as_str(), "[1, 2, 3]");
+
+        let mut s = Buf::new();
+        uwrite!(s, "{:?}", GenericArray::<u8, U0>::default()).unwrap();
+        assert_eq!(s.as_str(), "[]");
+    }
+
+    #[test]
+    fn test_display_hex() {
+        let mut s = Buf::new();
+        uwrite!(s, "{}", arr![10u8, 20, 0xFF]).unwrap();
+        assert_eq!(s.as_str(), "0a14ff");
+
+        // longer than the internal buffer, same as `core::fmt`
+        let array = GenericArray::<u8, U100>::from_iter(0..100);
+        let mut s = Buf::new();
+        uwrite!(s, "{}", array).unwrap();
+        let mut expected = Buf::new();
+        write!(expected, "{:x}", array).unwrap();
+        assert_eq!(s.as_str(), expected.as_str());
+
+        let mut s = Buf::new();
+        uwrite!(s, "{}", GenericArray::<u8, U0>::default()).unwrap();
+        assert_eq!(s.as_str(), "");
+    }
+}
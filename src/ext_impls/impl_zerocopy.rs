@@ -0,0 +1,120 @@
+//! `zerocopy` trait implementations
+//!
+//! `TryFromBytes` and `KnownLayout` are derived on `GenericArray` and its internal types
+//! instead, as they can't be implemented by hand, so they are bounded on the underlying
+//! array type. This is always satisfied for concrete lengths, given `T: TryFromBytes` or
+//! `T: KnownLayout`.
+//!
+//! The rest are implemented here, as `GenericArray<T, N>` has the same layout as `[T; N]`.
+
+use zerocopy::{FromBytes, FromZeros, Immutable, IntoBytes, TryFromBytes, Unaligned};
+
+use crate::{ArrayLength, GenericArray};
+
+// SAFETY: `GenericArray<T, N>` has the same layout as `[T; N]`, which is all zeroes if
+// every `T` is.
+unsafe impl<T: FromZeros, N: ArrayLength> FromZeros for GenericArray<T, N>
+where
+    GenericArray<T, N>: TryFromBytes,
+{
+    fn only_derive_is_allowed_to_implement_this_trait() {}
+}
+
+// SAFETY: `GenericArray<T, N>` has the same layout as `[T; N]`, which is valid for any
+// bytes if `T` is.
+unsafe impl<T: FromBytes, N: ArrayLength> FromBytes for GenericArray<T, N>
+where
+    GenericArray<T, N>: TryFromBytes,
+{
+    fn only_derive_is_allowed_to_implement_this_trait() {}
+}
+
+// SAFETY: `GenericArray<T, N>` has the same layout as `[T; N]`, which has no padding
+// or uninitialized bytes if `T` does not.
+unsafe impl<T: IntoBytes, N: ArrayLength> IntoBytes for GenericArray<T, N> {
+    fn only_derive_is_allowed_to_implement_this_trait() {}
+}
+
+// SAFETY: `GenericArray<T, N>` contains only `T`s, so it has no `UnsafeCell`s if `T` doesn't.
+unsafe impl<T: Immutable, N: ArrayLength> Immutable for GenericArray<T, N> {
+    fn only_derive_is_allowed_to_implement_this_trait() {}
+}
+
+// SAFETY: `GenericArray<T, N>` has the same alignment as `T`.
+unsafe impl<T: Unaligned, N: ArrayLength> Unaligned for GenericArray<T, N> {
+    fn only_derive_is_allowed_to_implement_this_trait() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use zerocopy::byteorder::{BigEndian, U16};
+    use zerocopy::{
+        FromBytes, FromZeros, Immutable, IntoBytes, KnownLayout, TryFromBytes, Unaligned,
+    };
+
+    use crate::typenum::{U0, U3, U4, U5};
+    use crate::{arr, GenericArray};
+
+    #[derive(Debug, FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+    #[repr(C)]
+    struct Header {
+        magic: GenericArray<u8, U4>,
+        len: U16<BigEndian>,
+        flags: GenericArray<u8, U3>,
+    }
+
+    #[test]
+    fn test_header() {
+        let bytes = [b'G', b'A', b'R', b'R', 0x01, 0x02, 7, 8, 9, 0xFF];
+
+        let (header, rest) = Header::ref_from_prefix(&bytes).unwrap();
+        assert_eq!(header.magic.as_slice(), b"GARR");
+        assert_eq!(header.len.get(), 0x0102);
+        assert_eq!(header.flags, arr![7, 8, 9]);
+        assert_eq!(rest, [0xFF]);
+
+        assert_eq!(header.as_bytes(), &bytes[..9]);
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let array = GenericArray::<u16, U3>::read_from_bytes(&[1, 0, 2, 0, 3, 0][..]).unwrap();
+        assert_eq!(
+            array,
+            arr![u16::from_le(1), u16::from_le(2), u16::from_le(3)]
+        );
+        assert_eq!(array.as_bytes(), [1, 0, 2, 0, 3, 0]);
+
+        assert!(GenericArray::<u8, U5>::ref_from_bytes(&[1, 2, 3][..]).is_err());
+
+        let mut bytes = [1u8, 2, 3, 4];
+        let array = GenericArray::<u8, U4>::mut_from_bytes(&mut bytes[..]).unwrap();
+        array[0] = 9;
+        assert_eq!(bytes, [9, 2, 3, 4]);
+
+        assert_eq!(GenericArray::<u32, U4>::new_zeroed(), arr![0; 4]);
+        assert!(GenericArray::<u8, U0>::read_from_bytes(&[][..]).is_ok());
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let array = GenericArray::<bool, U3>::try_read_from_bytes(&[1, 0, 1][..]).unwrap();
+        assert_eq!(array, arr![true, false, true]);
+
+        assert!(GenericArray::<bool, U3>::try_read_from_bytes(&[1, 2, 1][..]).is_err());
+        assert!(GenericArray::<bool, U3>::try_ref_from_bytes(&[1, 0][..]).is_err());
+
+        let nested = GenericArray::<GenericArray<bool, U3>, U3>::try_ref_from_bytes(
+            &[1, 0, 1, 0, 1, 0, 1, 1, 1][..],
+        )
+        .unwrap();
+        assert_eq!(nested[2], arr![true, true, true]);
+    }
+
+    #[test]
+    fn test_zeroed() {
+        let mut array = arr![1u32, 2, 3];
+        array.zero();
+        assert_eq!(array, arr![0, 0, 0]);
+    }
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "alloc")]
-mod impl_alloc;
+pub(crate) mod impl_alloc;
 
 #[cfg(feature = "const-default")]
 mod impl_const_default;
@@ -30,3 +30,42 @@ mod impl_rkyv_0_8;
 
 #[cfg(feature = "bytecheck-0_8")]
 mod impl_bytecheck_0_8;
+
+#[cfg(feature = "borsh")]
+mod impl_borsh;
+
+#[cfg(feature = "bincode-2")]
+mod impl_bincode_2;
+
+#[cfg(feature = "parity-scale-codec")]
+mod impl_parity_scale_codec;
+
+#[cfg(feature = "postcard")]
+mod impl_postcard;
+
+#[cfg(feature = "zerocopy")]
+mod impl_zerocopy;
+
+#[cfg(feature = "schemars")]
+mod impl_schemars;
+
+#[cfg(feature = "proptest")]
+pub(crate) mod impl_proptest;
+
+#[cfg(feature = "quickcheck")]
+mod impl_quickcheck;
+
+#[cfg(feature = "rand")]
+mod impl_rand;
+
+#[cfg(feature = "defmt")]
+mod impl_defmt;
+
+#[cfg(feature = "ufmt")]
+mod impl_ufmt;
+
+#[cfg(feature = "portable-simd")]
+mod impl_portable_simd;
+
+#[cfg(feature = "rayon")]
+mod impl_rayon;
@@ -0,0 +1,347 @@
+//! Collecting streams and joining futures into a [`GenericArray`], without heap allocation.
+//!
+//! Partially-filled arrays are kept by the returned futures between polls, and any elements
+//! already received are dropped if the future is dropped before it completes.
+//!
+//! ```rust
+//! # futures::executor::block_on(async {
+//! use futures::stream;
+//! use generic_array::{arr, futures::{join_all, StreamArrayExt}, typenum::U2};
+//!
+//! let mut s = stream::iter(1..=5);
+//! assert_eq!(s.next_array::<U2>().await, Some(arr![1, 2]));
+//! assert_eq!(s.next_array::<U2>().await, Some(arr![3, 4]));
+//! assert_eq!(s.next_array::<U2>().await, None);
+//!
+//! async fn call(shard: u32) -> u32 {
+//!     shard * 10
+//! }
+//!
+//! let replies = join_all(arr![call(1), call(2), call(3)]).await;
+//! assert_eq!(replies, arr![10, 20, 30]);
+//! # });
+//! ```
+
+use core::future::Future;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::{Stream, TryStream};
+
+use crate::functional::FunctionalSequence;
+use crate::internal::ArrayBuilder;
+use crate::{ArrayLength, GenericArray, GenericSequence, LengthError};
+
+/// Extension methods for collecting a [`Stream`] into `GenericArray`s.
+pub trait StreamArrayExt: Stream {
+    /// Waits for the next `N` items of the stream, returning them as an array.
+    ///
+    /// Resolves to `None` if the stream ends first, dropping any items received.
+    #[inline]
+    fn next_array<N: ArrayLength>(&mut self) -> NextArray<'_, Self, N>
+    where
+        Self: Unpin,
+    {
+        NextArray {
+            stream: self,
+            builder: ArrayBuilder::new(),
+        }
+    }
+
+    /// Collects the whole stream of `Result`s into an array, stopping at the first error.
+    ///
+    /// Like [`FallibleGenericSequence::try_generate`](crate::sequence::FallibleGenericSequence::try_generate),
+    /// the outer `Result` is for the array itself, which fails with [`LengthError`] if the stream
+    /// doesn't yield exactly `N` items, and the inner `Result` is the first error from the stream.
+    ///
+    /// ```rust
+    /// # futures::executor::block_on(async {
+    /// use futures::stream;
+    /// use generic_array::{arr, futures::StreamArrayExt, typenum::{U2, U3}};
+    ///
+    /// let replies = stream::iter([Ok::<_, ()>(1), Ok(2), Ok(3)]);
+    /// assert_eq!(replies.try_collect_array::<U3>().await.unwrap(), Ok(arr![1, 2, 3]));
+    ///
+    /// let replies = stream::iter([Ok(1), Err("timeout"), Ok(3)]);
+    /// assert_eq!(replies.try_collect_array::<U3>().await.unwrap(), Err("timeout"));
+    ///
+    /// let replies = stream::iter([Ok::<_, ()>(1), Ok(2), Ok(3)]);
+    /// assert!(replies.try_collect_array::<U2>().await.is_err());
+    /// # });
+    /// ```
+    #[inline]
+    fn try_collect_array<N: ArrayLength>(self) -> TryCollectArray<Self, N>
+    where
+        Self: TryStream + Sized,
+    {
+        TryCollectArray {
+            stream: self,
+            builder: ArrayBuilder::new(),
+        }
+    }
+}
+
+impl<S: Stream + ?Sized> StreamArrayExt for S {}
+
+/// Future for [`StreamArrayExt::next_array`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NextArray<'a, S: Stream + ?Sized, N: ArrayLength> {
+    stream: &'a mut S,
+    builder: ArrayBuilder<S::Item, N>,
+}
+
+// the items are never pinned
+impl<S: Stream + ?Sized, N: ArrayLength> Unpin for NextArray<'_, S, N> {}
+
+impl<S: Stream + Unpin + ?Sized, N: ArrayLength> Future for NextArray<'_, S, N> {
+    type Output = Option<GenericArray<S::Item, N>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        unsafe {
+            let (dst_iter, position) = this.builder.iter_position();
+
+            for dst in dst_iter.skip(*position) {
+                match Pin::new(&mut *this.stream).poll_next(cx) {
+                    Poll::Ready(Some(item)) => {
+                        dst.write(item);
+                        *position += 1;
+                    }
+                    Poll::Ready(None) => return Poll::Ready(None),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            Poll::Ready(Some(
+                mem::replace(&mut this.builder, ArrayBuilder::new()).assume_init(),
+            ))
+        }
+    }
+}
+
+/// Future for [`StreamArrayExt::try_collect_array`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TryCollectArray<S: TryStream, N: ArrayLength> {
+    stream: S,
+    builder: ArrayBuilder<S::Ok, N>,
+}
+
+// only the stream is pinned
+impl<S: TryStream + Unpin, N: ArrayLength> Unpin for TryCollectArray<S, N> {}
+
+impl<S: TryStream, N: ArrayLength> Future for TryCollectArray<S, N> {
+    type Output = Result<Result<GenericArray<S::Ok, N>, S::Error>, LengthError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the stream is never moved out of `self`
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut stream = unsafe { Pin::new_unchecked(&mut this.stream) };
+
+        unsafe {
+            let (dst_iter, position) = this.builder.iter_position();
+
+            for dst in dst_iter.skip(*position) {
+                match stream.as_mut().try_poll_next(cx) {
+                    Poll::Ready(Some(Ok(item))) => {
+                        dst.write(item);
+                        *position += 1;
+                    }
+                    Poll::Ready(Some(Err(e))) => return Poll::Ready(Ok(Err(e))),
+                    Poll::Ready(None) => return Poll::Ready(Err(LengthError)),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+
+        // the array is full, so the stream must end here
+        Poll::Ready(match futures_core::ready!(stream.try_poll_next(cx)) {
+            Some(Ok(_)) => Err(LengthError),
+            Some(Err(e)) => Ok(Err(e)),
+            None => Ok(Ok(unsafe {
+                mem::replace(&mut this.builder, ArrayBuilder::new()).assume_init()
+            })),
+        })
+    }
+}
+
+/// Polls all of the futures concurrently, resolving to an array of their outputs in order.
+///
+/// The futures and their outputs are stored inline in the returned future.
+#[inline]
+pub fn join_all<F: Future, N: ArrayLength>(futures: GenericArray<F, N>) -> JoinAll<F, N> {
+    JoinAll {
+        elems: futures.map(MaybeDone::Future),
+    }
+}
+
+/// Future for [`join_all`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct JoinAll<F: Future, N: ArrayLength> {
+    elems: GenericArray<MaybeDone<F>, N>,
+}
+
+enum MaybeDone<F: Future> {
+    Future(F),
+    Done(F::Output),
+    Gone,
+}
+
+// only the futures are pinned, not their outputs
+impl<F: Future + Unpin, N: ArrayLength> Unpin for JoinAll<F, N> {}
+
+impl<F: Future, N: ArrayLength> Future for JoinAll<F, N> {
+    type Output = GenericArray<F::Output, N>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: pending futures are only ever dropped in place
+        let elems = unsafe { &mut self.get_unchecked_mut().elems };
+
+        let mut all_done = true;
+
+        for elem in elems.iter_mut() {
+            if let MaybeDone::Future(f) = elem {
+                match unsafe { Pin::new_unchecked(f) }.poll(cx) {
+                    Poll::Ready(output) => *elem = MaybeDone::Done(output),
+                    Poll::Pending => all_done = false,
+                }
+            }
+        }
+
+        if !all_done {
+            return Poll::Pending;
+        }
+
+        Poll::Ready(GenericArray::generate(|i| {
+            match mem::replace(&mut elems[i], MaybeDone::Gone) {
+                MaybeDone::Done(output) => output,
+                _ => panic!("JoinAll polled after completion"),
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+
+    use futures::executor::block_on;
+    use futures::stream::{self, Stream};
+    use futures::task::noop_waker_ref;
+
+    use super::{join_all, StreamArrayExt};
+    use crate::sequence::GenericSequence;
+    use crate::typenum::{U0, U2, U3};
+    use crate::GenericArray;
+
+    /// Yields `Pending` before each item of `items`
+    fn pending_between<T>(items: impl IntoIterator<Item = T>) -> impl Stream<Item = T> + Unpin {
+        let mut items = items.into_iter();
+        let mut ready = false;
+
+        stream::poll_fn(move |cx| {
+            ready = !ready;
+
+            if ready {
+                Poll::Ready(items.next())
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+    }
+
+    #[test]
+    fn next_array() {
+        let mut s = pending_between(1..=7);
+
+        assert_eq!(block_on(s.next_array::<U3>()), Some(arr![1, 2, 3]));
+        assert_eq!(block_on(s.next_array::<U3>()), Some(arr![4, 5, 6]));
+        assert_eq!(block_on(s.next_array::<U0>()), Some(arr![]));
+        assert_eq!(block_on(s.next_array::<U3>()), None);
+    }
+
+    #[test]
+    fn next_array_drops_partial() {
+        struct Counted<'a>(&'a Cell<u32>);
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut s = pending_between([Counted(&drops), Counted(&drops), Counted(&drops)]);
+
+        {
+            let mut fut = s.next_array::<U3>();
+            let mut cx = Context::from_waker(noop_waker_ref());
+
+            // each poll receives one item before the stream is pending again
+            for _ in 0..2 {
+                assert!(pin!(&mut fut).poll(&mut cx).is_pending());
+            }
+        }
+
+        assert_eq!(drops.get(), 2);
+        drop(s);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn try_collect_array() {
+        let ok = |n: u32| pending_between((1..=n).map(Ok::<_, &str>));
+
+        assert_eq!(
+            block_on(ok(3).try_collect_array::<U3>()).unwrap(),
+            Ok(arr![1, 2, 3])
+        );
+        assert!(block_on(ok(2).try_collect_array::<U3>()).is_err());
+        assert!(block_on(ok(4).try_collect_array::<U3>()).is_err());
+        assert_eq!(
+            block_on(ok(0).try_collect_array::<U0>()).unwrap(),
+            Ok(arr![])
+        );
+
+        let s = pending_between([Ok(1), Err("nope"), Ok(3)]);
+        assert_eq!(block_on(s.try_collect_array::<U3>()).unwrap(), Err("nope"));
+
+        let s = pending_between([Ok(1), Ok(2), Err("extra")]);
+        assert_eq!(block_on(s.try_collect_array::<U2>()).unwrap(), Err("extra"));
+    }
+
+    #[test]
+    fn join_all_in_order() {
+        // each future is pending a different number of times
+        let futures = GenericArray::<_, U3>::generate(|i| {
+            let mut polls = 3 - i;
+
+            core::future::poll_fn(move |cx| {
+                if polls == 0 {
+                    Poll::Ready(i * 10)
+                } else {
+                    polls -= 1;
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+        });
+
+        assert_eq!(block_on(join_all(futures)), arr![0, 10, 20]);
+
+        // `!Unpin` futures are fine too
+        async fn double(x: u32) -> u32 {
+            x * 2
+        }
+
+        assert_eq!(block_on(join_all(arr![double(1), double(2)])), arr![2, 4]);
+
+        let empty = GenericArray::<core::future::Ready<u8>, U0>::from_array([]);
+        assert_eq!(block_on(join_all(empty)), arr![]);
+    }
+}
@@ -36,7 +36,7 @@ fn hex_encode_fallback<const UPPER: bool>(src: &[u8], dst: &mut [u8]) {
 }
 
 #[inline]
-fn hex_encode<const UPPER: bool>(src: &[u8], dst: &mut [u8]) {
+pub(crate) fn hex_encode<const UPPER: bool>(src: &[u8], dst: &mut [u8]) {
     debug_assert!(dst.len() >= (src.len() * 2));
 
     #[cfg(any(miri, not(feature = "faster-hex")))]
@@ -395,3 +395,17 @@ impl<T, N: ArrayLength> Drop for IntrusiveArrayConsumer<'_, T, N> {
         }
     }
 }
+
+/// **UNSAFE**: Drops the elements of a raw slice when dropped.
+///
+/// Used to keep dropping the rest of a collection that is split into several slices,
+/// such as a ring buffer, even if dropping an element of an earlier slice panics.
+pub(crate) struct SliceDropGuard<T>(pub(crate) *mut [T]);
+
+impl<T> Drop for SliceDropGuard<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.0);
+        }
+    }
+}
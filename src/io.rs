@@ -0,0 +1,273 @@
+//! Reading and writing fixed-size byte arrays with `std::io` and `embedded-io`.
+//!
+//! [`ReadExactArray`] and [`EmbeddedReadExactArray`] read a whole `GenericArray<u8, N>`
+//! from a reader, such as a fixed-size header, and [`ArrayCursor`] writes into one.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use generic_array::io::{ArrayCursor, ReadExactArray};
+//! use generic_array::typenum::{U4, U8};
+//! use std::io::Write;
+//!
+//! let mut cursor = ArrayCursor::<U8>::new();
+//! cursor.write_all(b"GA\x01\x00").unwrap();
+//! assert_eq!(cursor.remaining(), 4);
+//!
+//! let mut reader = cursor.written();
+//! let magic = reader.read_exact_array::<U4>().unwrap();
+//! assert_eq!(magic.as_slice(), b"GA\x01\x00");
+//! # }
+//! ```
+
+#[cfg(feature = "embedded-io-0_7")]
+use embedded_io_0_7 as embedded_io;
+
+use crate::{ArrayLength, GenericArray};
+
+/// Reading an exact number of bytes from a [`std::io::Read`] directly into a `GenericArray`.
+#[cfg(feature = "std")]
+pub trait ReadExactArray: std::io::Read {
+    /// Reads exactly `N` bytes, like [`read_exact`](std::io::Read::read_exact).
+    ///
+    /// The array is zeroed before reading into it, since `Read` implementations are allowed
+    /// to read from the buffer they're given, but the bytes land in the returned array with no
+    /// intermediate `[u8; N]` or copy.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`ErrorKind::UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) if the reader
+    /// ends before `N` bytes were read, or with any other error from the reader. The contents of
+    /// the reader are unspecified in either case, the same as for `read_exact`.
+    #[inline]
+    fn read_exact_array<N: ArrayLength>(&mut self) -> std::io::Result<GenericArray<u8, N>> {
+        let mut array = GenericArray::default();
+        self.read_exact(&mut array)?;
+        Ok(array)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read + ?Sized> ReadExactArray for R {}
+
+/// Reading an exact number of bytes from an [`embedded_io::Read`](embedded_io_0_7::Read)
+/// (version 0.7) directly into a `GenericArray`.
+#[cfg(feature = "embedded-io-0_7")]
+pub trait EmbeddedReadExactArray: embedded_io::Read {
+    /// Reads exactly `N` bytes, like [`read_exact`](embedded_io_0_7::Read::read_exact).
+    ///
+    /// As with [`ReadExactArray::read_exact_array`], the array is zeroed first.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`ReadExactError::UnexpectedEof`](embedded_io_0_7::ReadExactError::UnexpectedEof)
+    /// if the reader ends before `N` bytes were read, or with any error from the reader.
+    #[inline]
+    fn read_exact_array<N: ArrayLength>(
+        &mut self,
+    ) -> Result<GenericArray<u8, N>, embedded_io::ReadExactError<Self::Error>> {
+        let mut array = GenericArray::default();
+        self.read_exact(&mut array)?;
+        Ok(array)
+    }
+}
+
+#[cfg(feature = "embedded-io-0_7")]
+impl<R: embedded_io::Read + ?Sized> EmbeddedReadExactArray for R {}
+
+/// A writer that fills a `GenericArray<u8, N>` from the start.
+///
+/// Once all `N` bytes have been written, `std::io::Write` reports short writes and
+/// `embedded_io::Write` fails with `SliceWriteError::Full`, the same as writing to `&mut [u8]`.
+#[derive(Debug, Clone)]
+pub struct ArrayCursor<N: ArrayLength> {
+    // Invariant: position <= N
+    array: GenericArray<u8, N>,
+    position: usize,
+}
+
+impl<N: ArrayLength> ArrayCursor<N> {
+    /// Creates a cursor at the start of a zeroed array.
+    ///
+    /// This method is `const` since Rust 1.75.0, but non-`const` before.
+    #[rustversion::attr(since(1.75), const)]
+    #[inline(always)]
+    pub fn new() -> Self {
+        ArrayCursor {
+            // SAFETY: all-zero is a valid `u8` array
+            array: unsafe { core::mem::MaybeUninit::zeroed().assume_init() },
+            position: 0,
+        }
+    }
+
+    /// Returns the number of bytes written so far.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns how many more bytes can be written.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        N::USIZE - self.position
+    }
+
+    /// Returns `true` if all `N` bytes have been written.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.position == N::USIZE
+    }
+
+    /// Returns the bytes written so far.
+    #[inline]
+    pub fn written(&self) -> &[u8] {
+        &self.array[..self.position]
+    }
+
+    /// Moves the cursor back to the start, so the array will be overwritten.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.position = 0;
+    }
+
+    /// Returns the whole underlying array, including any bytes not yet written.
+    #[inline]
+    pub fn into_inner(self) -> GenericArray<u8, N> {
+        self.array
+    }
+
+    /// Copies as much of `buf` as fits, returning how many bytes were copied
+    #[inline]
+    fn write_some(&mut self, buf: &[u8]) -> usize {
+        let amt = Ord::min(buf.len(), self.remaining());
+        self.array[self.position..self.position + amt].copy_from_slice(&buf[..amt]);
+        self.position += amt;
+        amt
+    }
+}
+
+impl<N: ArrayLength> Default for ArrayCursor<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<N: ArrayLength> std::io::Write for ArrayCursor<N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.write_some(buf))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "embedded-io-0_7")]
+impl<N: ArrayLength> embedded_io::ErrorType for ArrayCursor<N> {
+    type Error = embedded_io::SliceWriteError;
+}
+
+#[cfg(feature = "embedded-io-0_7")]
+impl<N: ArrayLength> embedded_io::Write for ArrayCursor<N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if !buf.is_empty() && self.is_full() {
+            return Err(embedded_io::SliceWriteError::Full);
+        }
+
+        Ok(self.write_some(buf))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArrayCursor;
+    use crate::typenum::{U2, U4};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_read() {
+        use super::ReadExactArray;
+        use crate::typenum::U0;
+
+        let mut reader: &[u8] = &[1, 2, 3, 4, 5];
+
+        assert_eq!(reader.read_exact_array::<U2>().unwrap(), arr![1, 2]);
+        assert_eq!(reader.read_exact_array::<U0>().unwrap(), arr![]);
+
+        let err = reader.read_exact_array::<U4>().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        // through a trait object
+        let reader: &mut dyn std::io::Read = &mut &[6u8, 7][..];
+        assert_eq!(reader.read_exact_array::<U2>().unwrap(), arr![6, 7]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_write() {
+        use std::io::Write;
+
+        let mut cursor = ArrayCursor::<U4>::new();
+        assert_eq!(cursor.remaining(), 4);
+
+        assert_eq!(cursor.write(&[1, 2, 3]).unwrap(), 3);
+        assert_eq!(cursor.written(), [1, 2, 3]);
+        assert_eq!(cursor.remaining(), 1);
+
+        assert_eq!(cursor.write(&[4, 5]).unwrap(), 1);
+        assert!(cursor.is_full());
+        assert_eq!(cursor.write(&[6]).unwrap(), 0);
+
+        let err = cursor.write_all(&[6]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+        assert_eq!(cursor.clone().into_inner(), arr![1, 2, 3, 4]);
+
+        cursor.clear();
+        write!(cursor, "{}", 98).unwrap();
+        assert_eq!(cursor.position(), 2);
+        assert_eq!(cursor.into_inner(), arr![b'9', b'8', 3, 4]);
+    }
+
+    #[cfg(feature = "embedded-io-0_7")]
+    #[test]
+    fn embedded_read() {
+        use super::{embedded_io::ReadExactError, EmbeddedReadExactArray};
+
+        let mut reader: &[u8] = &[1, 2, 3, 4, 5];
+
+        assert_eq!(reader.read_exact_array::<U2>(), Ok(arr![1, 2]));
+        assert_eq!(
+            reader.read_exact_array::<U4>(),
+            Err(ReadExactError::UnexpectedEof)
+        );
+    }
+
+    #[cfg(feature = "embedded-io-0_7")]
+    #[test]
+    fn embedded_write() {
+        use super::embedded_io::{SliceWriteError, Write};
+
+        let mut cursor = ArrayCursor::<U4>::default();
+
+        assert_eq!(cursor.write(&[1, 2, 3]), Ok(3));
+        assert_eq!(cursor.write(&[4, 5]), Ok(1));
+        assert_eq!(cursor.write(&[]), Ok(0));
+        assert_eq!(cursor.write(&[6]), Err(SliceWriteError::Full));
+        assert_eq!(cursor.into_inner(), arr![1, 2, 3, 4]);
+
+        let mut cursor = ArrayCursor::<U4>::new();
+        assert_eq!(
+            cursor.write_all(&[1, 2, 3, 4, 5]),
+            Err(SliceWriteError::Full)
+        );
+    }
+}
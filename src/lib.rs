@@ -132,6 +132,7 @@
 //!     "zeroize",          # Zeroize implementation for setting array elements to zero
 //!     "const-default",    # Compile-time const default value support via trait
 //!     "alloc",            # Enables From/TryFrom implementations between GenericArray and Vec<T>/Box<[T]>
+//!     "std",              # Enables `alloc`, plus `std::io` support in the `io` module
 //!     "faster-hex",       # Enables internal use of the `faster-hex` crate for faster hex encoding via SIMD
 //!     "subtle",           # Enables `subtle` crate support for constant-time equality checks and conditional selection
 //!     "arbitrary",        # Enables `arbitrary` crate support for fuzzing
@@ -139,13 +140,35 @@
 //!     "bitvec",           # Enables `bitvec` crate support to use GenericArray as a storage backend for bit arrays
 //!     "as_slice",         # Enables `as-slice` crate trait impls
 //!     "compat-0_14",      # Enables interoperability with `generic-array` 0.14
-//!     "hybrid-array-0_4"  # Enables interoperability with `hybrid-array` 0.4
+//!     "hybrid-array-0_4", # Enables interoperability with `hybrid-array` 0.4
+//!     "heapless-0_9",     # Enables conversions with `heapless` 0.9 `Vec`
+//!     "arrayvec-0_7",     # Enables conversions with `arrayvec` 0.7 `ArrayVec`
+//!     "tinyvec-1",        # Enables conversions with `tinyvec` `ArrayVec`
+//!     "nalgebra-0_35",    # Enables views and conversions with `nalgebra` 0.35 vectors and matrices
+//!     "ndarray-0_17",     # Enables views and conversions with `ndarray` 0.17 arrays
+//!     "portable-simd",    # Nightly only, conversions with `core::simd::Simd` and vectorized map/zip
+//!     "rayon",            # Parallel iterators, and parallel generate/map into boxed arrays
+//!     "embedded-io-0_7",  # `embedded-io` 0.7 support in the `io` module
+//!     "futures",          # Collecting streams and joining futures into arrays, in the `futures` module
+//!     "borsh",            # BorshSerialize/BorshDeserialize implementation
+//!     "borsh-schema",     # BorshSchema implementation, using the unstable `borsh` schema support
+//!     "bincode-2",        # Native bincode 2 Encode/Decode/BorrowDecode implementation
+//!     "parity-scale-codec", # SCALE Encode/Decode/MaxEncodedLen and scale-info TypeInfo implementation
+//!     "postcard",         # postcard MaxSize implementation, for sizing buffers at compile time (no `Schema`)
+//!     "zerocopy",         # Enables `zerocopy` crate support, for GenericArray fields in zerocopy-derived structs
+//!     "schemars",         # JsonSchema implementation, an array of exactly N items
+//!     "proptest",         # Enables `proptest` crate support, with element-wise shrinking strategies
+//!     "quickcheck",       # Enables `quickcheck` crate support for property testing
+//!     "rand",             # Enables `rand` crate support for random arrays
+//!     "defmt",            # defmt::Format implementation, with byte arrays logged as hex
+//!     "ufmt"              # ufmt uDebug implementation, and uDisplay as hex for byte arrays
 //! ]
 //! ```
 
 #![no_std]
 #![deny(missing_docs, meta_variable_misuse, clippy::missing_safety_doc)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 
 pub extern crate typenum;
 
@@ -153,6 +176,9 @@ pub extern crate typenum;
 #[cfg(feature = "alloc")]
 pub extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod compat;
 mod hex;
 mod impls;
@@ -179,8 +205,18 @@ use typenum::uint::{UInt, UTerm, Unsigned};
 #[cfg_attr(test, macro_use)]
 pub mod arr;
 
+pub mod bitset;
+pub mod block_buffer;
+pub mod builder;
+pub mod deque;
 pub mod functional;
+#[cfg(feature = "futures")]
+pub mod futures;
+#[cfg(any(feature = "std", feature = "embedded-io-0_7"))]
+pub mod io;
+pub mod padding;
 pub mod sequence;
+pub mod string;
 
 mod internal;
 
@@ -200,12 +236,30 @@ pub mod internals {
     pub use crate::internal::{ArrayBuilder, ArrayConsumer};
 }
 
+/// [`proptest`](https://docs.rs/proptest) strategies for `GenericArray`
+#[cfg(feature = "proptest")]
+pub mod proptest {
+    pub use crate::ext_impls::impl_proptest::{uniform, ArrayValueTree, UniformArrayStrategy};
+}
+
 use internal::{IntrusiveArrayBuilder, IntrusiveArrayConsumer, Sealed};
 
 use self::functional::*;
 use self::sequence::*;
 
+pub use self::bitset::GenericBitSet;
+pub use self::block_buffer::BlockBuffer;
+pub use self::builder::GenericArrayBuilder;
+pub use self::deque::GenericDeque;
 pub use self::iter::GenericArrayIter;
+pub use self::string::GenericString;
+
+#[cfg(any(
+    feature = "heapless-0_9",
+    feature = "arrayvec-0_7",
+    feature = "tinyvec-1"
+))]
+pub use self::compat::ExtendFromGenericArray;
 
 /// `ArrayLength` is a type-level [`Unsigned`] integer used to
 /// define the number of elements in a [`GenericArray`].
@@ -345,6 +399,10 @@ pub type ConstGenericArray<T, const N: usize> = GenericArray<T, ConstArrayLength
 
 /// Internal type used to generate a struct of appropriate size
 #[allow(dead_code)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::TryFromBytes, zerocopy::KnownLayout)
+)]
 #[repr(C)]
 #[doc(hidden)]
 pub struct GenericArrayImplEven<T, U> {
@@ -354,6 +412,10 @@ pub struct GenericArrayImplEven<T, U> {
 
 /// Internal type used to generate a struct of appropriate size
 #[allow(dead_code)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::TryFromBytes, zerocopy::KnownLayout)
+)]
 #[repr(C)]
 #[doc(hidden)]
 pub struct GenericArrayImplOdd<T, U> {
@@ -539,6 +601,11 @@ where
 /// ```
 ///
 /// </details>
+// `TryFromBytes` and `KnownLayout` can only be derived, see `ext_impls/impl_zerocopy.rs`
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::TryFromBytes, zerocopy::KnownLayout)
+)]
 #[repr(transparent)]
 pub struct GenericArray<T, N: ArrayLength> {
     #[allow(dead_code)] // data is never accessed directly
@@ -1128,6 +1195,85 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
         unsafe { crate::const_transmute(self) }
     }
 
+    /// Convert a native array of any length into `GenericArray`, failing if `U != N`.
+    ///
+    /// Unlike [`from_array`](GenericArray::from_array), this is not limited to the lengths
+    /// supported by [`Const<U>`], such as 3000 elements:
+    ///
+    /// ```rust
+    /// # use generic_array::{GenericArray, typenum::{U1000, U3, Prod}};
+    /// type U3000 = Prod<U1000, U3>;
+    ///
+    /// let table = GenericArray::<u8, U3000>::try_from_array([7u8; 3000]).unwrap();
+    /// assert_eq!(table.len(), 3000);
+    ///
+    /// assert!(GenericArray::<u8, U3000>::try_from_array([7u8; 2999]).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_array<const U: usize>(value: [T; U]) -> Result<Self, LengthError> {
+        if U != N::USIZE {
+            return Err(LengthError);
+        }
+
+        let value = ManuallyDrop::new(value);
+
+        // SAFETY: `GenericArray<T, N>` has the same layout as `[T; N]`, and `U == N`
+        Ok(unsafe { ptr::read(&*value as *const [T; U] as *const Self) })
+    }
+
+    /// Convert the `GenericArray` into a native array of any length, failing if `U != N`.
+    ///
+    /// The array is returned unchanged in the error case.
+    ///
+    /// See [`try_from_array`](GenericArray::try_from_array) for more information.
+    #[inline]
+    pub fn try_into_array<const U: usize>(self) -> Result<[T; U], Self> {
+        if U != N::USIZE {
+            return Err(self);
+        }
+
+        let value = ManuallyDrop::new(self);
+
+        // SAFETY: `GenericArray<T, N>` has the same layout as `[T; N]`, and `U == N`
+        Ok(unsafe { ptr::read(&*value as *const Self as *const [T; U]) })
+    }
+
+    /// Convert a native array of any length into `GenericArray`, asserting that `U == N`
+    /// at compile time.
+    ///
+    /// This is the `const` equivalent of [`try_from_array`](GenericArray::try_from_array),
+    /// for lengths beyond those supported by [`Const<U>`].
+    ///
+    /// ```rust
+    /// # use generic_array::{GenericArray, typenum::{U1000, U3, Prod}};
+    /// const TABLE: GenericArray<u16, Prod<U1000, U3>> = GenericArray::from_array_asserted([1; 3000]);
+    /// ```
+    ///
+    /// A length mismatch fails to compile, though only once the function is instantiated,
+    /// so it may not be reported by `cargo check`:
+    ///
+    /// ```compile_fail
+    /// # use generic_array::{GenericArray, typenum::{U1000, U3, Prod}};
+    /// let table = GenericArray::<u16, Prod<U1000, U3>>::from_array_asserted([1; 2999]);
+    /// ```
+    #[inline(always)]
+    pub const fn from_array_asserted<const U: usize>(value: [T; U]) -> Self {
+        let () = LengthAsserter::<N, U>::ASSERT_LENGTH_EQUALITY;
+
+        unsafe { crate::const_transmute(value) }
+    }
+
+    /// Convert the `GenericArray` into a native array of any length, asserting that `U == N`
+    /// at compile time.
+    ///
+    /// See [`from_array_asserted`](GenericArray::from_array_asserted) for more information.
+    #[inline(always)]
+    pub const fn into_array_asserted<const U: usize>(self) -> [T; U] {
+        let () = LengthAsserter::<N, U>::ASSERT_LENGTH_EQUALITY;
+
+        unsafe { crate::const_transmute(self) }
+    }
+
     /// Convert a slice of native arrays into a slice of `GenericArray`s.
     #[inline(always)]
     pub const fn from_chunks<const U: usize>(chunks: &[[T; U]]) -> &[GenericArray<T, N>]
@@ -1218,9 +1364,139 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
     pub const unsafe fn assume_init(array: GenericArray<MaybeUninit<T>, N>) -> Self {
         const_transmute::<GenericArray<MaybeUninit<T>, N>, GenericArray<T, N>>(array)
     }
+
+    /// Converts an uninitialized array into an array of uninitialized elements.
+    ///
+    /// This is the `GenericArray` equivalent of the unstable `MaybeUninit::<[T; N]>::transpose`,
+    /// and the inverse of [`GenericArray::transpose`].
+    #[inline(always)]
+    pub const fn transpose_uninit(uninit: MaybeUninit<Self>) -> GenericArray<MaybeUninit<T>, N> {
+        // SAFETY: `MaybeUninit<GenericArray<T, N>>` has the same layout as `GenericArray<MaybeUninit<T>, N>`
+        unsafe { const_transmute(uninit) }
+    }
+}
+
+impl<T, N: ArrayLength> GenericArray<MaybeUninit<T>, N> {
+    /// Converts an array of uninitialized elements into an uninitialized array.
+    ///
+    /// This is the `GenericArray` equivalent of the unstable `<[MaybeUninit<T>; N]>::transpose`,
+    /// and the inverse of [`GenericArray::transpose_uninit`].
+    #[inline(always)]
+    pub const fn transpose(self) -> MaybeUninit<GenericArray<T, N>> {
+        // SAFETY: `GenericArray<MaybeUninit<T>, N>` has the same layout as `MaybeUninit<GenericArray<T, N>>`
+        unsafe { const_transmute(self) }
+    }
+
+    /// Copies the elements of `src` into the array, returning it as initialized.
+    ///
+    /// Any previous contents are overwritten without being dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` is not exactly `N` elements long, like [`slice::copy_from_slice`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use generic_array::{GenericArray, typenum::U3, arr};
+    /// let mut array = GenericArray::<u8, U3>::uninit();
+    ///
+    /// let init = array.write_from_slice(&[1, 2, 3]);
+    /// init[0] = 0;
+    ///
+    /// assert_eq!(*init, arr![0, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn write_from_slice(&mut self, src: &[T]) -> &mut GenericArray<T, N>
+    where
+        T: Copy,
+    {
+        // SAFETY: `&[T]` has the same layout as `&[MaybeUninit<T>]`, and is only read from
+        let uninit_src = unsafe { &*(src as *const [T] as *const [MaybeUninit<T>]) };
+
+        self.copy_from_slice(uninit_src);
+
+        // SAFETY: every element was just written
+        unsafe { self.assume_init_mut() }
+    }
+
+    /// Clones the elements of `src` into the array, returning it as initialized.
+    ///
+    /// Any previous contents are overwritten without being dropped. If a `clone` panics,
+    /// the elements cloned so far are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` is not exactly `N` elements long, like [`slice::clone_from_slice`].
+    #[inline]
+    pub fn write_clone_from_slice(&mut self, src: &[T]) -> &mut GenericArray<T, N>
+    where
+        T: Clone,
+    {
+        assert_eq!(
+            src.len(),
+            N::USIZE,
+            "source slice length does not match GenericArray length"
+        );
+
+        unsafe {
+            let mut builder = IntrusiveArrayBuilder::new(self);
+            builder.extend(src.iter().cloned());
+            builder.finish();
+
+            // SAFETY: every element was just written
+            self.assume_init_mut()
+        }
+    }
+
+    /// Views the array as initialized.
+    ///
+    /// # Safety
+    ///
+    /// All elements of the array must be initialized.
+    #[inline(always)]
+    pub const unsafe fn assume_init_ref(&self) -> &GenericArray<T, N> {
+        &*(self as *const Self as *const GenericArray<T, N>)
+    }
+
+    /// Views the array as initialized, mutably.
+    ///
+    /// # Safety
+    ///
+    /// All elements of the array must be initialized.
+    #[rustversion::attr(since(1.83), const)]
+    #[inline(always)]
+    pub unsafe fn assume_init_mut(&mut self) -> &mut GenericArray<T, N> {
+        &mut *(self as *mut Self as *mut GenericArray<T, N>)
+    }
+
+    /// Drops every element of the array in place, leaving them uninitialized.
+    ///
+    /// # Safety
+    ///
+    /// All elements of the array must be initialized, and are uninitialized afterwards.
+    #[inline]
+    pub unsafe fn assume_init_drop(&mut self) {
+        ptr::drop_in_place(self.assume_init_mut().as_mut_slice());
+    }
+}
+
+impl<T, N: ArrayLength> From<MaybeUninit<GenericArray<T, N>>> for GenericArray<MaybeUninit<T>, N> {
+    #[inline(always)]
+    fn from(uninit: MaybeUninit<GenericArray<T, N>>) -> Self {
+        GenericArray::transpose_uninit(uninit)
+    }
+}
+
+impl<T, N: ArrayLength> From<GenericArray<MaybeUninit<T>, N>> for MaybeUninit<GenericArray<T, N>> {
+    #[inline(always)]
+    fn from(array: GenericArray<MaybeUninit<T>, N>) -> Self {
+        array.transpose()
+    }
 }
 
-/// Error type for [`TryFrom`] and [`try_from_iter`](GenericArray::try_from_iter) implementations.
+/// Error type for [`TryFrom`], [`try_from_iter`](GenericArray::try_from_iter) and
+/// [`try_from_array`](GenericArray::try_from_array) implementations.
 #[derive(Debug, Clone, Copy)]
 pub struct LengthError;
 
@@ -1233,6 +1509,20 @@ impl core::fmt::Display for LengthError {
     }
 }
 
+/// Error type for appending to a fixed-capacity container that does not have enough room left,
+/// such as [`GenericString::push_str`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+#[rustversion::since(1.81)]
+impl core::error::Error for CapacityError {}
+
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("CapacityError: Insufficient capacity remaining")
+    }
+}
+
 /// Error type for heap allocation failures.
 ///
 /// Returned by [`FallibleGenericSequence::try_generate`](sequence::FallibleGenericSequence::try_generate)
@@ -1280,20 +1570,48 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
     where
         I: IntoIterator<Item = T>,
     {
-        let mut iter = iter.into_iter();
+        let iter = Self::check_iter_len(iter)?;
+
+        let mut array = MaybeUninit::<GenericArray<T, N>>::uninit();
+
+        Self::try_fill_from_iter(&mut array, iter)?;
+
+        // SAFETY: the array was filled
+        Ok(unsafe { array.assume_init() })
+    }
+
+    /// Rejects iterators whose `size_hint` rules out yielding exactly `N` elements,
+    /// so callers can bail out before allocating space for the array.
+    #[inline]
+    pub(crate) fn check_iter_len<I>(iter: I) -> Result<I::IntoIter, LengthError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
 
-        // pre-checks
         match iter.size_hint() {
             // if the lower bound is greater than N, array will overflow
-            (n, _) if n > N::USIZE => return Err(LengthError),
+            (n, _) if n > N::USIZE => Err(LengthError),
             // if the upper bound is smaller than N, array cannot be filled
-            (_, Some(n)) if n < N::USIZE => return Err(LengthError),
-            _ => {}
+            (_, Some(n)) if n < N::USIZE => Err(LengthError),
+            _ => Ok(iter),
         }
+    }
 
+    /// Writes exactly `N` elements from `iter` into `dst` in place, such as into a heap allocation.
+    ///
+    /// Size hints should be checked beforehand with [`check_iter_len`](Self::check_iter_len).
+    /// On failure, any elements already written are dropped and `dst` is left uninitialized.
+    #[inline]
+    pub(crate) fn try_fill_from_iter<I>(
+        dst: &mut MaybeUninit<GenericArray<T, N>>,
+        mut iter: I,
+    ) -> Result<(), LengthError>
+    where
+        I: Iterator<Item = T>,
+    {
         unsafe {
-            let mut array = MaybeUninit::<GenericArray<T, N>>::uninit();
-            let mut builder = IntrusiveArrayBuilder::new_alt(&mut array);
+            let mut builder = IntrusiveArrayBuilder::new_alt(dst);
 
             builder.extend(&mut iter);
 
@@ -1301,8 +1619,10 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
                 return Err(LengthError);
             }
 
-            Ok(builder.finish_and_assume_init())
+            builder.finish();
         }
+
+        Ok(())
     }
 
     /// Fallible equivalent of [`FromFallibleIterator::from_fallible_iter`].
@@ -1347,6 +1667,17 @@ impl<T, N: ArrayLength> GenericArray<T, N> {
     }
 }
 
+/// Compile-time check that a native array of length `U` matches `N`
+struct LengthAsserter<N, const U: usize>(PhantomData<N>);
+
+impl<N: ArrayLength, const U: usize> LengthAsserter<N, U> {
+    const ASSERT_LENGTH_EQUALITY: () = {
+        if N::USIZE != U {
+            panic!("Length mismatch between native array and GenericArray");
+        }
+    };
+}
+
 /// A const reimplementation of the [`transmute`](core::mem::transmute) function,
 /// avoiding problems when the compiler can't prove equal sizes for some reason.
 ///
@@ -1402,6 +1733,9 @@ mod test {
     //      -C target-cpu=native -C opt-level=3 --emit asm
     // and view the assembly to make sure test_assembly generates
     // SIMD instructions instead of a naive loop.
+    //
+    // On nightly, the `portable-simd` feature provides `simd_map`/`simd_zip`,
+    // which use explicit SIMD vectors rather than relying on auto-vectorization.
 
     #[inline(never)]
     pub fn black_box<T>(val: T) -> T {
@@ -0,0 +1,295 @@
+//! One-shot block padding and unpadding into `GenericArray<u8, N>` blocks.
+//!
+//! Each padding scheme is a type implementing [`Padding`], which can pad a partial block
+//! of fewer than `N` bytes into a full `GenericArray<u8, N>` block, and validate and strip
+//! the padding of a full block again.
+//!
+//! ```rust
+//! use generic_array::padding::{Padding, Pkcs7};
+//! use generic_array::{arr, typenum::U8};
+//!
+//! let block = Pkcs7::pad::<U8>(b"hello").unwrap();
+//! assert_eq!(block, arr![b'h', b'e', b'l', b'l', b'o', 3, 3, 3]);
+//!
+//! assert_eq!(Pkcs7::unpad(&block).unwrap(), b"hello");
+//! assert!(Pkcs7::unpad(&arr![0u8; 8]).is_err());
+//! ```
+//!
+//! With the `subtle` feature, `Padding::unpad_ct` validates the padding in constant time,
+//! without revealing where or whether the padding is malformed through timing.
+
+use core::fmt;
+
+#[cfg(feature = "subtle")]
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    CtOption,
+};
+
+use crate::internal::Sealed;
+use crate::{ArrayLength, GenericArray, LengthError};
+
+/// Error type for [`Padding::unpad`], returned when a block is not correctly padded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnpadError;
+
+#[rustversion::since(1.81)]
+impl core::error::Error for UnpadError {}
+
+impl fmt::Display for UnpadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("UnpadError: Block is not correctly padded")
+    }
+}
+
+/// A block padding scheme.
+///
+/// This trait is sealed and implemented by [`Pkcs7`], [`AnsiX923`], [`Iso7816`] and [`ZeroPadding`].
+pub trait Padding: Sealed {
+    /// Pads `data` into a full block.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is not shorter than `N`, as there must always be
+    /// room for at least one byte of padding.
+    fn pad<N: ArrayLength>(data: &[u8]) -> Result<GenericArray<u8, N>, LengthError>;
+
+    /// Validates the padding of `block`, and returns the data before it.
+    ///
+    /// This may return early at the first invalid byte, so it should not be used where
+    /// timing could act as a padding oracle. See `unpad_ct` instead, with the `subtle` feature.
+    fn unpad<N: ArrayLength>(block: &GenericArray<u8, N>) -> Result<&[u8], UnpadError>;
+
+    /// Validates the padding of `block` in constant time, and returns the data before it.
+    ///
+    /// If the padding is invalid, the returned value is none, and the slice it
+    /// holds is meaningless.
+    #[cfg(feature = "subtle")]
+    fn unpad_ct<N: ArrayLength>(block: &GenericArray<u8, N>) -> CtOption<&[u8]>;
+}
+
+/// Copies `data` into the front of a zeroed block, if there is room for at least one byte after it.
+#[inline]
+fn copy_data<N: ArrayLength>(data: &[u8]) -> Result<GenericArray<u8, N>, LengthError> {
+    if data.len() >= N::USIZE {
+        return Err(LengthError);
+    }
+
+    let mut block = GenericArray::<u8, N>::default();
+    block[..data.len()].copy_from_slice(data);
+    Ok(block)
+}
+
+/// Checks that `N` can be represented by a trailing length byte.
+#[inline(always)]
+fn assert_length_byte<N: ArrayLength>() {
+    assert!(
+        N::USIZE <= 255,
+        "padding length does not fit in a byte for blocks larger than 255 bytes"
+    );
+}
+
+/// Returns the padding length stored in the last byte of `block`, if it is in `1..=N`.
+#[inline]
+fn length_byte<N: ArrayLength>(block: &GenericArray<u8, N>) -> Result<usize, UnpadError> {
+    match block.last() {
+        Some(&n) if n != 0 && n as usize <= N::USIZE => Ok(n as usize),
+        _ => Err(UnpadError),
+    }
+}
+
+/// Finds the index and value of the last non-zero byte of `block` in constant time,
+/// along with whether there is one at all.
+#[cfg(feature = "subtle")]
+#[inline]
+fn last_non_zero_ct<N: ArrayLength>(block: &GenericArray<u8, N>) -> (Choice, u64, u8) {
+    let mut found = Choice::from(0);
+    let mut index = 0u64;
+    let mut value = 0u8;
+
+    for (i, b) in block.iter().enumerate() {
+        let non_zero = !b.ct_eq(&0);
+
+        index.conditional_assign(&(i as u64), non_zero);
+        value.conditional_assign(b, non_zero);
+        found |= non_zero;
+    }
+
+    (found, index, value)
+}
+
+/// Shared constant-time check of schemes with the padding length in the last byte.
+///
+/// `fill` gives the expected value of each padding byte before the length byte, given the length byte.
+#[cfg(feature = "subtle")]
+#[inline]
+fn unpad_length_byte_ct<N: ArrayLength>(
+    block: &GenericArray<u8, N>,
+    fill: impl Fn(u8) -> u8,
+) -> CtOption<&[u8]> {
+    let n = match block.last() {
+        Some(&n) => n,
+        None => return CtOption::new(&[], Choice::from(0)),
+    };
+
+    let block_len = N::USIZE as u64;
+    let mut valid = !n.ct_eq(&0) & !(n as u64).ct_gt(&block_len);
+
+    // wraps if invalid, which is harmless as the start is only used when valid
+    let start = block_len.wrapping_sub(n as u64);
+
+    // rebuild the block as it should be if the padding were valid, and compare it to the
+    // original all at once, rather than failing at the first unexpected byte
+    let mut expected = block.clone();
+
+    for (i, b) in expected[..N::USIZE - 1].iter_mut().enumerate() {
+        b.conditional_assign(&fill(n), valid & !(i as u64).ct_lt(&start));
+    }
+
+    valid &= expected.ct_eq(block);
+
+    let len = u64::conditional_select(&0, &start, valid);
+    CtOption::new(&block[..len as usize], valid)
+}
+
+/// PKCS#7 padding, where each padding byte is the number of padding bytes.
+///
+/// Only block sizes of up to 255 bytes are supported.
+#[derive(Debug, Clone, Copy)]
+pub enum Pkcs7 {}
+
+/// ANSI X9.23 padding, where the padding is zeroes followed by a final byte with the
+/// number of padding bytes.
+///
+/// Only block sizes of up to 255 bytes are supported.
+#[derive(Debug, Clone, Copy)]
+pub enum AnsiX923 {}
+
+/// ISO/IEC 7816-4 padding, where the padding is a single `0x80` byte followed by zeroes.
+#[derive(Debug, Clone, Copy)]
+pub enum Iso7816 {}
+
+/// Zero padding, where the padding is any number of zeroes.
+///
+/// Unpadding strips all trailing zeroes and never fails, so this is only suitable for data
+/// which cannot end with a zero byte.
+#[derive(Debug, Clone, Copy)]
+pub enum ZeroPadding {}
+
+impl Sealed for Pkcs7 {}
+impl Sealed for AnsiX923 {}
+impl Sealed for Iso7816 {}
+impl Sealed for ZeroPadding {}
+
+impl Padding for Pkcs7 {
+    /// # Panics
+    ///
+    /// Panics if `N` is greater than 255.
+    #[inline]
+    fn pad<N: ArrayLength>(data: &[u8]) -> Result<GenericArray<u8, N>, LengthError> {
+        assert_length_byte::<N>();
+
+        let mut block = copy_data::<N>(data)?;
+        block[data.len()..].fill((N::USIZE - data.len()) as u8);
+        Ok(block)
+    }
+
+    #[inline]
+    fn unpad<N: ArrayLength>(block: &GenericArray<u8, N>) -> Result<&[u8], UnpadError> {
+        let n = length_byte(block)?;
+        let (data, padding) = block.split_at(N::USIZE - n);
+
+        match padding.iter().all(|&b| b as usize == n) {
+            true => Ok(data),
+            false => Err(UnpadError),
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[inline]
+    fn unpad_ct<N: ArrayLength>(block: &GenericArray<u8, N>) -> CtOption<&[u8]> {
+        unpad_length_byte_ct(block, |n| n)
+    }
+}
+
+impl Padding for AnsiX923 {
+    /// # Panics
+    ///
+    /// Panics if `N` is greater than 255.
+    #[inline]
+    fn pad<N: ArrayLength>(data: &[u8]) -> Result<GenericArray<u8, N>, LengthError> {
+        assert_length_byte::<N>();
+
+        let mut block = copy_data::<N>(data)?;
+        block[N::USIZE - 1] = (N::USIZE - data.len()) as u8;
+        Ok(block)
+    }
+
+    #[inline]
+    fn unpad<N: ArrayLength>(block: &GenericArray<u8, N>) -> Result<&[u8], UnpadError> {
+        let n = length_byte(block)?;
+        let (data, padding) = block.split_at(N::USIZE - n);
+
+        match padding[..n - 1].iter().all(|&b| b == 0) {
+            true => Ok(data),
+            false => Err(UnpadError),
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[inline]
+    fn unpad_ct<N: ArrayLength>(block: &GenericArray<u8, N>) -> CtOption<&[u8]> {
+        unpad_length_byte_ct(block, |_| 0)
+    }
+}
+
+impl Padding for Iso7816 {
+    #[inline]
+    fn pad<N: ArrayLength>(data: &[u8]) -> Result<GenericArray<u8, N>, LengthError> {
+        let mut block = copy_data::<N>(data)?;
+        block[data.len()] = 0x80;
+        Ok(block)
+    }
+
+    #[inline]
+    fn unpad<N: ArrayLength>(block: &GenericArray<u8, N>) -> Result<&[u8], UnpadError> {
+        match block.iter().rposition(|&b| b != 0) {
+            Some(i) if block[i] == 0x80 => Ok(&block[..i]),
+            _ => Err(UnpadError),
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[inline]
+    fn unpad_ct<N: ArrayLength>(block: &GenericArray<u8, N>) -> CtOption<&[u8]> {
+        let (found, index, value) = last_non_zero_ct(block);
+        let valid = found & value.ct_eq(&0x80);
+
+        let len = u64::conditional_select(&0, &index, valid);
+        CtOption::new(&block[..len as usize], valid)
+    }
+}
+
+impl Padding for ZeroPadding {
+    #[inline]
+    fn pad<N: ArrayLength>(data: &[u8]) -> Result<GenericArray<u8, N>, LengthError> {
+        copy_data::<N>(data)
+    }
+
+    #[inline]
+    fn unpad<N: ArrayLength>(block: &GenericArray<u8, N>) -> Result<&[u8], UnpadError> {
+        match block.iter().rposition(|&b| b != 0) {
+            Some(i) => Ok(&block[..i + 1]),
+            None => Ok(&[]),
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[inline]
+    fn unpad_ct<N: ArrayLength>(block: &GenericArray<u8, N>) -> CtOption<&[u8]> {
+        let (found, index, _) = last_non_zero_ct(block);
+
+        let len = u64::conditional_select(&0, &(index + 1), found);
+        CtOption::new(&block[..len as usize], Choice::from(1))
+    }
+}
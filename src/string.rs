@@ -0,0 +1,453 @@
+//! Fixed-capacity UTF-8 string backed by a [`GenericArray`].
+//!
+//! [`GenericString<N>`](GenericString) stores up to `N` bytes of UTF-8 inline,
+//! without any heap allocation.
+//!
+//! ```rust
+//! use core::fmt::Write;
+//! use generic_array::{string::GenericString, typenum::U8};
+//!
+//! let mut s = GenericString::<U8>::new();
+//! s.push_str("hello").unwrap();
+//! write!(s, "{}", 42).unwrap();
+//! assert_eq!(s, "hello42");
+//!
+//! // does not fit, so nothing is appended
+//! assert!(s.push_str("!!").is_err());
+//! assert_eq!(s.len(), 7);
+//!
+//! // `str` methods are available through `Deref`
+//! assert!(s.starts_with("hell"));
+//! ```
+
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+use core::{fmt, str};
+
+use crate::{ArrayLength, CapacityError, GenericArray};
+
+/// A fixed-capacity UTF-8 string, holding up to `N` bytes inline.
+///
+/// Appending more than the remaining capacity fails with [`CapacityError`]
+/// and leaves the string unchanged.
+pub struct GenericString<N: ArrayLength> {
+    // Invariants: len <= N, buf[..len] is valid UTF-8 and buf[len..] is all zeroes
+    pub(crate) buf: GenericArray<u8, N>,
+    pub(crate) len: usize,
+}
+
+impl<N: ArrayLength> GenericString<N> {
+    /// Creates an empty string.
+    ///
+    /// This method is `const` since Rust 1.75.0, but non-`const` before.
+    #[rustversion::attr(since(1.75), const)]
+    #[inline(always)]
+    pub fn new() -> Self {
+        GenericString {
+            // SAFETY: all-zero is a valid `u8` array
+            buf: unsafe { core::mem::MaybeUninit::zeroed().assume_init() },
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum length of the string in bytes, `N`.
+    #[inline(always)]
+    pub const fn capacity() -> usize {
+        N::USIZE
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the string has a length of zero.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes that can still be appended.
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        N::USIZE - self.len
+    }
+
+    /// Returns `true` if no more bytes can be appended.
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len == N::USIZE
+    }
+
+    /// Extracts a string slice containing the entire string.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `buf[..len]` is always valid UTF-8
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Extracts a mutable string slice containing the entire string.
+    #[inline(always)]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        // SAFETY: `buf[..len]` is always valid UTF-8
+        unsafe { str::from_utf8_unchecked_mut(&mut self.buf[..self.len]) }
+    }
+
+    /// Returns the bytes of the string.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Appends a string slice to the end of this string.
+    ///
+    /// If `s` does not fit in the remaining capacity, nothing is appended.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let end = match self.len.checked_add(s.len()) {
+            Some(end) if end <= N::USIZE => end,
+            _ => return Err(CapacityError),
+        };
+
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+
+    /// Appends a single character to the end of this string.
+    ///
+    /// If the UTF-8 encoding of `c` does not fit in the remaining capacity, nothing is appended.
+    #[inline]
+    pub fn push(&mut self, c: char) -> Result<(), CapacityError> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Removes the last character from the string and returns it,
+    /// or `None` if the string is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.truncate(self.len - c.len_utf8());
+        Some(c)
+    }
+
+    /// Shortens the string to `new_len` bytes.
+    ///
+    /// If `new_len` is greater than the current length, this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a `char` boundary.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len is not a char boundary"
+            );
+
+            self.buf[new_len..self.len].fill(0);
+            self.len = new_len;
+        }
+    }
+
+    /// Truncates the string to zero length.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns the underlying byte array.
+    ///
+    /// Only the first [`len()`](Self::len) bytes belong to the string; the rest are always zero.
+    #[inline(always)]
+    pub const fn as_array(&self) -> &GenericArray<u8, N> {
+        &self.buf
+    }
+
+    /// Decomposes the string into its underlying byte array and length in bytes.
+    ///
+    /// Only the first `len` bytes belong to the string; the rest are always zero.
+    #[inline(always)]
+    pub fn into_parts(self) -> (GenericArray<u8, N>, usize) {
+        (self.buf, self.len)
+    }
+
+    /// Creates a string from the first `len` bytes of `buf`.
+    ///
+    /// Any bytes of `buf` past `len` are zeroed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf[..len]` is not valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len > N`.
+    #[inline]
+    pub fn from_parts(mut buf: GenericArray<u8, N>, len: usize) -> Result<Self, str::Utf8Error> {
+        str::from_utf8(&buf[..len])?;
+        buf[len..].fill(0);
+
+        Ok(GenericString { buf, len })
+    }
+}
+
+impl<N: ArrayLength> Default for GenericString<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: ArrayLength> Clone for GenericString<N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        GenericString {
+            buf: self.buf.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<N: ArrayLength> Copy for GenericString<N> where GenericArray<u8, N>: Copy {}
+
+impl<N: ArrayLength> Deref for GenericString<N> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<N: ArrayLength> DerefMut for GenericString<N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<N: ArrayLength> AsRef<str> for GenericString<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<N: ArrayLength> AsMut<str> for GenericString<N> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<N: ArrayLength> AsRef<[u8]> for GenericString<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<N: ArrayLength> Borrow<str> for GenericString<N> {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<N: ArrayLength> BorrowMut<str> for GenericString<N> {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<N: ArrayLength> fmt::Debug for GenericString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<N: ArrayLength> fmt::Display for GenericString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<N: ArrayLength> fmt::Write for GenericString<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c).map_err(|_| fmt::Error)
+    }
+}
+
+impl<N: ArrayLength> str::FromStr for GenericString<N> {
+    type Err = CapacityError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, CapacityError> {
+        let mut string = Self::new();
+        string.push_str(s)?;
+        Ok(string)
+    }
+}
+
+impl<'a, N: ArrayLength> TryFrom<&'a str> for GenericString<N> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, CapacityError> {
+        s.parse()
+    }
+}
+
+impl<N: ArrayLength, M: ArrayLength> PartialEq<GenericString<M>> for GenericString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &GenericString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<N: ArrayLength> Eq for GenericString<N> {}
+
+impl<N: ArrayLength> PartialEq<str> for GenericString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a, N: ArrayLength> PartialEq<&'a str> for GenericString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<N: ArrayLength> PartialEq<GenericString<N>> for str {
+    #[inline(always)]
+    fn eq(&self, other: &GenericString<N>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<N: ArrayLength> PartialEq<GenericString<N>> for &str {
+    #[inline(always)]
+    fn eq(&self, other: &GenericString<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<N: ArrayLength, M: ArrayLength> PartialOrd<GenericString<M>> for GenericString<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &GenericString<M>) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<N: ArrayLength> Ord for GenericString<N> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<N: ArrayLength> PartialOrd<str> for GenericString<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        self.as_str().partial_cmp(other)
+    }
+}
+
+impl<N: ArrayLength> Hash for GenericString<N> {
+    // must hash the same as `str`, for consistency with `Borrow<str>`
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// An archived [`GenericString`].
+///
+/// Stores the full `N`-byte buffer followed by the length, so the archived
+/// form has a fixed size and needs no out-of-line data.
+#[cfg(feature = "rkyv-0_8")]
+#[repr(C)]
+pub struct ArchivedGenericString<N: ArrayLength> {
+    // Same invariants as `GenericString`, checked by `CheckBytes` when validating
+    pub(crate) buf: GenericArray<u8, N>,
+    pub(crate) len: rkyv_0_8::Archived<usize>,
+}
+
+#[cfg(feature = "rkyv-0_8")]
+impl<N: ArrayLength> ArchivedGenericString<N> {
+    /// Returns the length of the string in bytes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len.to_native() as usize
+    }
+
+    /// Returns `true` if the string has a length of zero.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Extracts a string slice containing the entire string.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `buf[..len]` is valid UTF-8 in any archive produced by rkyv
+        // or validated by `CheckBytes`
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len()]) }
+    }
+}
+
+#[cfg(feature = "rkyv-0_8")]
+impl<N: ArrayLength> Deref for ArchivedGenericString<N> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "rkyv-0_8")]
+impl<N: ArrayLength> fmt::Debug for ArchivedGenericString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(feature = "rkyv-0_8")]
+impl<N: ArrayLength> fmt::Display for ArchivedGenericString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(feature = "rkyv-0_8")]
+impl<N: ArrayLength, M: ArrayLength> PartialEq<GenericString<M>> for ArchivedGenericString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &GenericString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "rkyv-0_8")]
+impl<N: ArrayLength> PartialEq<str> for ArchivedGenericString<N> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
@@ -40,6 +40,20 @@ fn test_try_boxed_from_iter_wrong_length() {
     assert_eq!(&ok[..], &[0, 1, 2, 3]);
 }
 
+// A terabyte-sized array can't be allocated, so this only passes if the
+// size_hint pre-checks run before the allocation.
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_try_from_iter_wrong_length_does_not_allocate() {
+    use generic_array::typenum::{Exp, U2, U40};
+
+    type Huge = GenericArray<u8, Exp<U2, U40>>;
+
+    assert!(Huge::try_boxed_from_iter(0..4).is_err());
+    assert!(Huge::try_rc_from_iter(0..4).is_err());
+    assert!(Huge::try_arc_from_iter(0..4).is_err());
+}
+
 #[test]
 #[should_panic]
 fn test_boxed_from_iter_wrong_length() {
@@ -523,3 +537,84 @@ fn panic_unwind_from_iter_drops_all_once() {
     assert!(result.is_err(), "iterator should have panicked");
     assert_eq!(counter.get(), 3, "initialized prefix dropped exactly once");
 }
+
+#[test]
+fn test_rc_arc_from_iter() {
+    use alloc::{rc::Rc, sync::Arc};
+
+    let rc = GenericArray::<i32, U4>::generate_rc(|i| i as i32 * 2);
+    assert_eq!(*rc, arr![0, 2, 4, 6]);
+
+    let arc = GenericArray::<i32, U4>::generate_arc(|i| i as i32 + 1);
+    assert_eq!(*arc, arr![1, 2, 3, 4]);
+
+    assert!(GenericArray::<i32, U4>::try_rc_from_iter(0..3).is_err());
+    assert!(GenericArray::<i32, U4>::try_arc_from_iter(0..5).is_err());
+
+    // iterators without a size hint are still checked
+    let mut short = 0..3;
+    let short = core::iter::from_fn(move || short.next());
+    assert!(GenericArray::<i32, U4>::try_arc_from_iter(short).is_err());
+
+    let empty: Rc<GenericArray<Tracked, U0>> =
+        GenericArray::try_rc_from_iter(core::iter::empty()).unwrap();
+    assert!(empty.is_empty());
+
+    let zst = GenericArray::<(), U4>::generate_arc(|_| ());
+    assert_eq!(zst.len(), 4);
+    drop(Arc::clone(&zst));
+}
+
+#[test]
+fn test_rc_arc_from_iter_drops() {
+    let counter = Cell::new(0u32);
+
+    // hides the size hint, so the elements are actually created before the length check fails
+    let unhinted = |n| {
+        let mut iter = (0..n).map(|i| Tracked(i, &counter));
+        core::iter::from_fn(move || iter.next())
+    };
+
+    // a short iterator drops the elements already written
+    let short = unhinted(3);
+    assert!(GenericArray::<Tracked, U4>::try_rc_from_iter(short).is_err());
+    assert_eq!(counter.get(), 3);
+
+    // a long iterator drops the extra element it consumed, too
+    counter.set(0);
+    let long = unhinted(5);
+    assert!(GenericArray::<Tracked, U4>::try_arc_from_iter(long).is_err());
+    assert_eq!(counter.get(), 5);
+
+    counter.set(0);
+    let rc = GenericArray::<Tracked, U4>::generate_rc(|i| Tracked(i as i32, &counter));
+    let other = rc.clone();
+    drop(rc);
+    assert_eq!(counter.get(), 0);
+    drop(other);
+    assert_eq!(counter.get(), 4);
+}
+
+#[test]
+fn test_rc_arc_slice_conversions() {
+    use alloc::{rc::Rc, sync::Arc};
+
+    let slice: Arc<[i32]> = Arc::from(&[1, 2, 3, 4][..]);
+    let shared = slice.clone();
+
+    let array = GenericArray::<i32, U4>::try_from_arc_slice(slice).unwrap();
+    assert_eq!(*array, arr![1, 2, 3, 4]);
+    assert_eq!(Arc::strong_count(&array), 2);
+
+    let back = GenericArray::into_arc_slice(array);
+    assert!(Arc::ptr_eq(&back, &shared));
+
+    assert!(GenericArray::<i32, U0>::try_from_arc_slice(back).is_err());
+
+    let slice: Rc<[i32]> = Rc::from(alloc::vec![5, 6, 7, 8]);
+    let array = GenericArray::<i32, U4>::try_from_rc_slice(slice).unwrap();
+    assert_eq!(GenericArray::into_rc_slice(array)[..], [5, 6, 7, 8]);
+
+    let slice: Rc<[i32]> = Rc::from(&[1][..]);
+    assert!(GenericArray::<i32, U4>::try_from_rc_slice(slice).is_err());
+}
@@ -0,0 +1,173 @@
+use generic_array::bitset::{BitSetLength, GenericBitSet};
+use generic_array::typenum::{U0, U1, U130, U64, U8};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+#[test]
+fn test_set_clear_test() {
+    let mut set = GenericBitSet::<U130>::new();
+    assert!(set.is_empty());
+    assert_eq!(GenericBitSet::<U130>::len(), 130);
+
+    assert!(!set.set(0));
+    assert!(set.set(0));
+    assert!(!set.set(129));
+    assert!(!set.set(64));
+
+    assert!(set.test(0));
+    assert!(set.test(64));
+    assert!(set.test(129));
+    assert!(!set.test(1));
+
+    assert!(set.clear(64));
+    assert!(!set.clear(64));
+    assert!(!set.test(64));
+
+    assert!(!set.toggle(5));
+    assert!(set.toggle(5));
+    assert!(!set.test(5));
+
+    assert!(!set.put(7, true));
+    assert!(set.put(7, false));
+    assert!(!set.test(7));
+
+    assert_eq!(set.count_ones(), 2);
+    assert_eq!(set.count_zeros(), 128);
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_range() {
+    let set = GenericBitSet::<U130>::new();
+    set.test(130);
+}
+
+#[test]
+#[should_panic]
+fn test_set_out_of_range() {
+    let mut set = GenericBitSet::<U8>::new();
+    set.set(8);
+}
+
+#[test]
+fn test_first_last_iter() {
+    let mut set = GenericBitSet::<U130>::new();
+    assert_eq!(set.first_set(), None);
+    assert_eq!(set.last_set(), None);
+    assert_eq!(set.iter().next(), None);
+
+    set.extend([3, 63, 64, 100, 129]);
+
+    assert_eq!(set.first_set(), Some(3));
+    assert_eq!(set.last_set(), Some(129));
+
+    let iter = set.iter();
+    assert_eq!(iter.len(), 5);
+    assert!(iter.eq([3, 63, 64, 100, 129]));
+
+    let mut iter = (&set).into_iter();
+    iter.next();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(format!("{:?}", iter), "{63, 64, 100, 129}");
+    assert_eq!(format!("{:?}", set), "{3, 63, 64, 100, 129}");
+}
+
+#[test]
+#[allow(clippy::op_ref)] // exercise the by-reference operator impls
+fn test_set_operations() {
+    let a: GenericBitSet<U130> = [1, 2, 3, 100].into_iter().collect();
+    let b: GenericBitSet<U130> = [3, 4, 100, 129].into_iter().collect();
+
+    assert!((&a | &b).iter().eq([1, 2, 3, 4, 100, 129]));
+    assert!((&a & &b).iter().eq([3, 100]));
+    assert!((&a - &b).iter().eq([1, 2]));
+    assert!((&a ^ &b).iter().eq([1, 2, 4, 129]));
+
+    let mut c = a;
+    c |= &b;
+    assert_eq!(c, a | b);
+    c &= b;
+    assert_eq!(c, b);
+    c -= &a;
+    assert!(c.iter().eq([4, 129]));
+    c ^= b;
+    assert!(c.iter().eq([3, 100]));
+
+    assert!((&a & &b).is_subset(&a));
+    assert!(!a.is_subset(&b));
+    assert!((&a - &b).is_disjoint(&b));
+    assert!(!a.is_disjoint(&b));
+}
+
+#[test]
+fn test_not_and_fill() {
+    let mut set = GenericBitSet::<U130>::new();
+    set.set_all();
+    assert!(set.is_full());
+    assert_eq!(set.count_ones(), 130);
+    assert_eq!(set.last_set(), Some(129));
+
+    // complement must not set bits beyond `Bits`
+    let inv = !GenericBitSet::<U130>::new();
+    assert_eq!(inv, set);
+    assert!((!&inv).is_empty());
+
+    set.clear_all();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_words() {
+    let set = GenericBitSet::<U130>::from_words([!0; 3].into());
+    assert_eq!(set.count_ones(), 130);
+    assert_eq!(set.as_words()[2], 0b11);
+    assert_eq!(set.into_words()[0], !0);
+
+    let set = GenericBitSet::<U64>::from_words([!0].into());
+    assert!(set.is_full());
+}
+
+#[test]
+fn test_zero_and_one_bits() {
+    let set = GenericBitSet::<U0>::new();
+    assert!(set.is_empty());
+    assert!(set.is_full());
+    assert_eq!(set.first_set(), None);
+    assert_eq!(set.iter().count(), 0);
+    assert!((!set).is_empty());
+
+    let mut set = GenericBitSet::<U1>::new();
+    set.set(0);
+    assert!(set.is_full());
+    assert!((!set).is_empty());
+}
+
+#[rustversion::since(1.83)]
+#[test]
+fn test_const() {
+    const SET: GenericBitSet<U130> = {
+        let mut set = GenericBitSet::new();
+        set.set(1);
+        set.set(128);
+        set
+    };
+
+    const _: () = assert!(SET.test(128));
+
+    assert!(SET.iter().eq([1, 128]));
+}
+
+#[test]
+fn test_traits() {
+    fn hash_of<N: BitSetLength>(set: &GenericBitSet<N>) -> u64 {
+        let mut h = DefaultHasher::new();
+        set.hash(&mut h);
+        h.finish()
+    }
+
+    let a: GenericBitSet<U8> = [1, 2].into_iter().collect();
+    let b = a; // Copy
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+    assert_eq!(GenericBitSet::<U8>::default(), GenericBitSet::new());
+}
@@ -0,0 +1,152 @@
+use generic_array::block_buffer::BlockBuffer;
+use generic_array::typenum::{U0, U16, U4, U64};
+use generic_array::GenericArray;
+
+fn collect<N: generic_array::ArrayLength>(
+    buffer: &mut BlockBuffer<N>,
+    inputs: &[&[u8]],
+) -> Vec<Vec<u8>> {
+    let mut blocks = Vec::new();
+    for input in inputs {
+        buffer.digest_blocks(input, |block| blocks.push(block.to_vec()));
+    }
+    blocks
+}
+
+#[test]
+fn test_digest_blocks() {
+    let mut buffer = BlockBuffer::<U4>::new();
+    assert_eq!(BlockBuffer::<U4>::block_size(), 4);
+    assert_eq!(buffer.remaining(), 4);
+
+    let blocks = collect(&mut buffer, &[b"ab", b"c", b"defghij", b"k"]);
+    assert_eq!(blocks, [b"abcd".to_vec(), b"efgh".to_vec()]);
+    assert_eq!(buffer.data(), b"ijk");
+    assert_eq!(buffer.position(), 3);
+    assert_eq!(buffer.remaining(), 1);
+
+    // completing the buffered block exactly processes it right away
+    let blocks = collect(&mut buffer, &[b"l"]);
+    assert_eq!(blocks, [b"ijkl".to_vec()]);
+    assert_eq!(buffer.data(), b"");
+
+    let blocks = collect(&mut buffer, &[b"", b"mnopqrst"]);
+    assert_eq!(blocks, [b"mnop".to_vec(), b"qrst".to_vec()]);
+    assert_eq!(buffer.position(), 0);
+}
+
+#[test]
+fn test_reset() {
+    let mut buffer = BlockBuffer::<U4>::new();
+    collect(&mut buffer, &[b"abcdef"]);
+    assert_eq!(buffer.data(), b"ef");
+
+    buffer.reset();
+    assert_eq!(buffer.position(), 0);
+
+    // stale bytes must not leak into padding
+    assert_eq!(buffer.pad_with_zeros(), GenericArray::from_array([0; 4]));
+}
+
+#[test]
+fn test_zero_block_size() {
+    let mut buffer = BlockBuffer::<U0>::new();
+    assert_eq!(buffer.remaining(), 0);
+    assert!(collect(&mut buffer, &[b""]).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_zero_block_size_input() {
+    let mut buffer = BlockBuffer::<U0>::new();
+    collect(&mut buffer, &[b"a"]);
+}
+
+#[test]
+fn test_len64_padding_be() {
+    // "abc" with SHA-256 padding
+    let mut buffer = BlockBuffer::<U64>::new();
+    collect(&mut buffer, &[b"abc"]);
+
+    let mut blocks = Vec::new();
+    buffer.len64_padding_be(3 * 8, |block| blocks.push(block.to_vec()));
+
+    let mut expected = vec![0u8; 64];
+    expected[..4].copy_from_slice(b"abc\x80");
+    expected[63] = 24;
+    assert_eq!(blocks, [expected]);
+    assert_eq!(buffer.position(), 0);
+}
+
+#[test]
+fn test_len64_padding_overflow() {
+    // 56 bytes leave no room for the length after the `0x80` byte
+    let mut buffer = BlockBuffer::<U64>::new();
+    collect(&mut buffer, &[&[1; 56]]);
+
+    let mut blocks = Vec::new();
+    buffer.len64_padding_le(56 * 8, |block| blocks.push(block.to_vec()));
+    assert_eq!(blocks.len(), 2);
+
+    assert_eq!(&blocks[0][..56], &[1; 56][..]);
+    assert_eq!(&blocks[0][56..], b"\x80\0\0\0\0\0\0\0");
+    assert_eq!(&blocks[1][..56], &[0; 56][..]);
+    assert_eq!(&blocks[1][56..], &(56u64 * 8).to_le_bytes());
+
+    // 55 bytes still fit in a single block
+    collect(&mut buffer, &[&[1; 55]]);
+    let mut blocks = Vec::new();
+    buffer.len64_padding_be(55 * 8, |block| blocks.push(block.to_vec()));
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0][55], 0x80);
+    assert_eq!(&blocks[0][56..], &(55u64 * 8).to_be_bytes());
+}
+
+#[test]
+fn test_len128_padding_be() {
+    let mut buffer = BlockBuffer::<U64>::new();
+
+    let mut blocks = Vec::new();
+    buffer.len128_padding_be(0, |block| blocks.push(block.to_vec()));
+
+    let mut expected = vec![0u8; 64];
+    expected[0] = 0x80;
+    assert_eq!(blocks, [expected]);
+}
+
+#[test]
+#[should_panic]
+fn test_len128_padding_block_too_small() {
+    let mut buffer = BlockBuffer::<U16>::new();
+    buffer.len128_padding_be(0, |_| {});
+}
+
+#[test]
+fn test_block_padding() {
+    let mut buffer = BlockBuffer::<U4>::new();
+
+    collect(&mut buffer, &[b"a"]);
+    assert_eq!(buffer.pad_with_zeros().as_slice(), b"a\0\0\0");
+    assert_eq!(buffer.position(), 0);
+
+    collect(&mut buffer, &[b"ab"]);
+    assert_eq!(buffer.pad_with_pkcs7().as_slice(), b"ab\x02\x02");
+    assert_eq!(buffer.pad_with_pkcs7().as_slice(), b"\x04\x04\x04\x04");
+
+    collect(&mut buffer, &[b"abc"]);
+    assert_eq!(buffer.pad_with_iso7816().as_slice(), b"abc\x80");
+    assert_eq!(buffer.pad_with_iso7816().as_slice(), b"\x80\0\0\0");
+}
+
+#[test]
+fn test_clone_debug() {
+    let mut buffer = BlockBuffer::<U4>::new();
+    collect(&mut buffer, &[b"secret"]);
+
+    let copy = buffer.clone();
+    assert_eq!(copy.data(), b"et");
+    assert_eq!(
+        format!("{:?}", copy),
+        "BlockBuffer { block_size: 4, pos: 2, .. }"
+    );
+}
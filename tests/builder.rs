@@ -0,0 +1,135 @@
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use generic_array::typenum::{U0, U3, U4};
+use generic_array::{arr, GenericArray, GenericArrayBuilder};
+
+struct DropCounter<'a>(&'a Cell<u32>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn test_push_finish() {
+    let mut b = GenericArrayBuilder::<i32, U3>::new();
+    assert!(b.is_empty());
+    assert_eq!(GenericArrayBuilder::<i32, U3>::capacity(), 3);
+
+    b.push(1).unwrap();
+    b.push(2).unwrap();
+    assert_eq!(b.len(), 2);
+    assert_eq!(b.as_slice(), [1, 2]);
+
+    // not yet full, so the builder comes back unchanged
+    let mut b = b.try_finish().unwrap_err();
+    assert_eq!(b.len(), 2);
+
+    b.push(3).unwrap();
+    assert!(b.is_full());
+    assert_eq!(b.push(4), Err(4));
+
+    b.as_mut_slice()[0] = 10;
+    assert_eq!(format!("{:?}", b), "[10, 2, 3]");
+
+    assert_eq!(b.try_finish().ok(), Some(arr![10, 2, 3]));
+}
+
+#[test]
+fn test_pop_clear() {
+    let mut b = GenericArrayBuilder::<String, U4>::default();
+    assert_eq!(b.pop(), None);
+
+    b.push("a".into()).unwrap();
+    b.push("b".into()).unwrap();
+    assert_eq!(b.pop().as_deref(), Some("b"));
+    assert_eq!(b.as_slice(), ["a"]);
+
+    b.clear();
+    assert!(b.is_empty());
+    assert_eq!(b.pop(), None);
+}
+
+#[test]
+fn test_zero_length() {
+    let b = GenericArrayBuilder::<i32, U0>::new();
+    assert!(b.is_full() && b.is_empty());
+
+    let mut b = b;
+    assert_eq!(b.push(1), Err(1));
+    assert_eq!(
+        b.try_finish().ok(),
+        Some(GenericArray::<i32, U0>::from_array([]))
+    );
+}
+
+#[test]
+fn test_drops_partial() {
+    let drops = Cell::new(0);
+
+    {
+        let mut b = GenericArrayBuilder::<DropCounter, U4>::new();
+        for _ in 0..3 {
+            b.push(DropCounter(&drops)).ok().unwrap();
+        }
+
+        drop(b.pop());
+        assert_eq!(drops.get(), 1);
+    }
+
+    assert_eq!(drops.get(), 3);
+
+    // the value rejected by a full builder is handed back, and dropped here
+    drops.set(0);
+    let mut b = GenericArrayBuilder::<DropCounter, U3>::new();
+    while b.push(DropCounter(&drops)).is_ok() {}
+    assert_eq!(drops.get(), 1);
+
+    // a finished array owns the elements, so the builder drops nothing
+    let array = b.try_finish().ok().unwrap();
+    assert_eq!(drops.get(), 1);
+    drop(array);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn test_drops_on_panic() {
+    let drops = Cell::new(0);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let mut b = GenericArrayBuilder::<DropCounter, U4>::new();
+
+        for i in 0..4 {
+            if i == 2 {
+                panic!("early exit");
+            }
+
+            b.push(DropCounter(&drops)).ok().unwrap();
+        }
+
+        b.try_finish().ok().unwrap()
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn test_early_return() {
+    fn parse_all(input: &[&str]) -> Result<GenericArray<u8, U3>, std::num::ParseIntError> {
+        let mut b = GenericArrayBuilder::new();
+
+        for s in input {
+            // `?` drops the builder along with the elements parsed so far
+            let _ = b.push(s.parse()?);
+        }
+
+        Ok(b.try_finish()
+            .unwrap_or_else(|_| panic!("too few elements")))
+    }
+
+    assert_eq!(parse_all(&["1", "2", "3"]).unwrap(), arr![1, 2, 3]);
+    assert!(parse_all(&["1", "x", "3"]).is_err());
+}
@@ -0,0 +1,238 @@
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use generic_array::deque::GenericDeque;
+use generic_array::typenum::{U0, U4};
+use generic_array::{arr, GenericArray};
+
+#[test]
+fn test_push_pop() {
+    let mut d = GenericDeque::<i32, U4>::new();
+    assert!(d.is_empty());
+    assert_eq!(GenericDeque::<i32, U4>::capacity(), 4);
+    assert_eq!(d.pop_front(), None);
+    assert_eq!(d.pop_back(), None);
+    assert_eq!(d.front(), None);
+    assert_eq!(d.back(), None);
+
+    d.push_back(2).unwrap();
+    d.push_back(3).unwrap();
+    d.push_front(1).unwrap();
+    d.push_front(0).unwrap();
+    assert!(d.is_full());
+    assert_eq!(d.push_back(4), Err(4));
+    assert_eq!(d.push_front(-1), Err(-1));
+
+    assert_eq!(d.len(), 4);
+    assert_eq!(d.front(), Some(&0));
+    assert_eq!(d.back(), Some(&3));
+    assert_eq!(d.get(2), Some(&2));
+    assert_eq!(d.get(4), None);
+
+    *d.front_mut().unwrap() = 10;
+    *d.back_mut().unwrap() = 13;
+    *d.get_mut(1).unwrap() = 11;
+    assert!(d.iter().eq(&[10, 11, 2, 13]));
+
+    assert_eq!(d.pop_front(), Some(10));
+    assert_eq!(d.pop_back(), Some(13));
+    assert_eq!(d.pop_front(), Some(11));
+    assert_eq!(d.pop_back(), Some(2));
+    assert!(d.is_empty());
+}
+
+#[test]
+fn test_wrapping_slices() {
+    let mut d = GenericDeque::<i32, U4>::new();
+
+    d.push_back(1).unwrap();
+    d.push_back(2).unwrap();
+    assert_eq!(d.as_slices(), (&[1, 2][..], &[][..]));
+
+    d.push_front(0).unwrap();
+    assert_eq!(d.as_slices(), (&[0][..], &[1, 2][..]));
+
+    for x in d.as_mut_slices().1 {
+        *x *= 10;
+    }
+
+    assert!(d.iter().eq(&[0, 10, 20]));
+    assert!(d.iter().rev().eq(&[20, 10, 0]));
+    assert_eq!(d.iter().len(), 3);
+
+    for x in d.iter_mut() {
+        *x += 1;
+    }
+
+    assert!(d.iter_mut().rev().map(|x| *x).eq([21, 11, 1]));
+    assert_eq!(format!("{:?}", d), "[1, 11, 21]");
+}
+
+#[test]
+fn test_overwrite() {
+    let mut d = GenericDeque::<i32, U4>::new();
+
+    for i in 0..4 {
+        assert_eq!(d.push_back_overwrite(i), None);
+    }
+
+    assert_eq!(d.push_back_overwrite(4), Some(0));
+    assert_eq!(d.push_back_overwrite(5), Some(1));
+    assert!(d.iter().eq(&[2, 3, 4, 5]));
+
+    assert_eq!(d.push_front_overwrite(1), Some(5));
+    assert!(d.iter().eq(&[1, 2, 3, 4]));
+
+    let mut z = GenericDeque::<i32, U0>::new();
+    assert!(z.is_full() && z.is_empty());
+    assert_eq!(z.push_back(1), Err(1));
+    assert_eq!(z.push_back_overwrite(1), Some(1));
+    assert_eq!(z.push_front_overwrite(2), Some(2));
+    assert_eq!(z.as_slices(), (&[][..], &[][..]));
+}
+
+#[test]
+fn test_drain() {
+    let mut d = GenericDeque::<i32, U4>::new();
+    d.push_back(2).unwrap();
+    d.push_back(3).unwrap();
+    d.push_front(1).unwrap();
+
+    let mut drain = d.drain();
+    assert_eq!(drain.len(), 3);
+    assert_eq!(format!("{:?}", drain), "[1, 2, 3]");
+    assert_eq!(drain.next(), Some(1));
+    assert_eq!(drain.next_back(), Some(3));
+    drop(drain);
+
+    assert!(d.is_empty());
+
+    // usable again after draining
+    d.push_back(7).unwrap();
+    assert_eq!(d.pop_front(), Some(7));
+}
+
+#[test]
+fn test_conversions() {
+    let d = GenericDeque::from(arr![1, 2, 3, 4]);
+    assert!(d.is_full());
+
+    let d2 = d.clone();
+    assert_eq!(d, d2);
+
+    let mut iter = d.into_iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(4));
+    assert!(iter.eq([2, 3]));
+
+    let d3: GenericDeque<i32, U4> = GenericDeque::default();
+    assert_ne!(d2, d3);
+}
+
+#[derive(Clone)]
+struct DropCounter<'a>(&'a Cell<u32>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn test_drop_wrapped() {
+    let drops = Cell::new(0);
+
+    {
+        let mut d = GenericDeque::<DropCounter, U4>::new();
+        d.push_back(DropCounter(&drops)).ok().unwrap();
+        d.push_back(DropCounter(&drops)).ok().unwrap();
+        d.push_front(DropCounter(&drops)).ok().unwrap();
+
+        // evicted element is returned, and dropped here
+        d.push_back_overwrite(DropCounter(&drops));
+        d.push_back_overwrite(DropCounter(&drops));
+        assert_eq!(drops.get(), 1);
+    }
+
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn test_drain_drops_remaining() {
+    let drops = Cell::new(0);
+
+    let mut d = GenericDeque::<DropCounter, U4>::new();
+    for _ in 0..3 {
+        d.push_front(DropCounter(&drops)).ok().unwrap();
+    }
+
+    let mut drain = d.drain();
+    drain.next();
+    assert_eq!(drops.get(), 1);
+    drop(drain);
+    assert_eq!(drops.get(), 3);
+
+    // leaking the drain leaks the elements, but leaves the deque empty
+    for _ in 0..3 {
+        d.push_back(DropCounter(&drops)).ok().unwrap();
+    }
+
+    std::mem::forget(d.drain());
+    assert!(d.is_empty());
+    drop(d);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn test_drop_panic_drops_rest() {
+    struct PanicOnDrop<'a>(&'a Cell<u32>, bool);
+
+    impl Drop for PanicOnDrop<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+
+            if self.1 {
+                panic!("PanicOnDrop");
+            }
+        }
+    }
+
+    let drops = Cell::new(0);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let mut d = GenericDeque::<PanicOnDrop, U4>::new();
+        d.push_back(PanicOnDrop(&drops, false)).ok().unwrap();
+        d.push_back(PanicOnDrop(&drops, false)).ok().unwrap();
+        d.push_front(PanicOnDrop(&drops, true)).ok().unwrap();
+        d.push_front(PanicOnDrop(&drops, false)).ok().unwrap();
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn test_clone_panic() {
+    struct PanicOnClone<'a>(&'a Cell<u32>);
+
+    impl Clone for PanicOnClone<'_> {
+        fn clone(&self) -> Self {
+            if self.0.get() == 2 {
+                panic!("PanicOnClone");
+            }
+
+            self.0.set(self.0.get() + 1);
+            PanicOnClone(self.0)
+        }
+    }
+
+    let clones = Cell::new(0);
+    let d = GenericDeque::from(GenericArray::<_, U4>::from_iter(
+        (0..4).map(|_| PanicOnClone(&clones)),
+    ));
+
+    let result = catch_unwind(AssertUnwindSafe(|| d.clone()));
+    assert!(result.is_err());
+    assert_eq!(clones.get(), 2);
+}
@@ -423,6 +423,75 @@ fn test_from_array_mut() {
     assert_eq!(&mut a_copy, a_from);
 }
 
+#[test]
+fn test_try_from_array() {
+    use generic_array::typenum::{Prod, U1000};
+
+    type U3000 = Prod<U1000, U3>;
+
+    let mut table = [0u16; 3000];
+    for (i, elem) in table.iter_mut().enumerate() {
+        *elem = i as u16;
+    }
+
+    let a = GenericArray::<u16, U3000>::try_from_array(table).unwrap();
+    assert_eq!(a[2999], 2999);
+    assert_eq!(a.try_into_array::<3000>().unwrap(), table);
+
+    assert!(GenericArray::<u16, U3000>::try_from_array([0u16; 3001]).is_err());
+    assert!(GenericArray::<u16, U3>::try_from_array([0u16; 0]).is_err());
+
+    // the array is handed back on mismatch
+    let a = arr![1, 2, 3];
+    assert_eq!(a.try_into_array::<4>(), Err(a));
+}
+
+#[test]
+fn test_try_from_array_drops() {
+    #[derive(Debug)]
+    struct TestDrop<'a>(&'a Cell<u32>);
+
+    impl<'a> Drop for TestDrop<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drop_counter = Cell::new(0);
+
+    let a = GenericArray::<_, U3>::try_from_array([
+        TestDrop(&drop_counter),
+        TestDrop(&drop_counter),
+        TestDrop(&drop_counter),
+    ])
+    .unwrap();
+    assert_eq!(drop_counter.get(), 0);
+
+    let a = a.try_into_array::<3>().unwrap();
+    assert_eq!(drop_counter.get(), 0);
+    drop(a);
+    assert_eq!(drop_counter.get(), 3);
+
+    // dropped exactly once on mismatch
+    let result = GenericArray::<_, U4>::try_from_array([TestDrop(&drop_counter)]);
+    assert!(result.is_err());
+    assert_eq!(drop_counter.get(), 4);
+}
+
+#[test]
+fn test_from_array_asserted() {
+    use generic_array::typenum::{Prod, U1000};
+
+    const TABLE: GenericArray<u8, Prod<U1000, U3>> = GenericArray::from_array_asserted([1; 3000]);
+    const BACK: [u8; 3000] = TABLE.into_array_asserted();
+
+    assert!(TABLE.iter().all(|&x| x == 1));
+    assert_eq!(BACK, [1; 3000]);
+
+    let a: GenericArray<i32, U4> = GenericArray::from_array_asserted([1, 2, 3, 4]);
+    assert_eq!(a, arr![1, 2, 3, 4]);
+}
+
 #[test]
 fn test_chunks() {
     // intended usage
@@ -1113,6 +1182,83 @@ fn test_from_fallible_iter_length_fail_panics() {
         FromFallibleIterator::from_fallible_iter((0..2).map(Ok::<i32, ()>));
 }
 
+#[test]
+fn test_uninit_transpose() {
+    use core::mem::MaybeUninit;
+
+    let mut uninit = GenericArray::<i32, U4>::transpose_uninit(MaybeUninit::uninit());
+    for (i, slot) in uninit.iter_mut().enumerate() {
+        slot.write(i as i32);
+    }
+
+    let array: MaybeUninit<GenericArray<i32, U4>> = uninit.into();
+    assert_eq!(unsafe { array.assume_init() }, arr![0, 1, 2, 3]);
+
+    let uninit = GenericArray::<i32, U0>::uninit().transpose();
+    assert_eq!(unsafe { uninit.assume_init() }, arr![]);
+}
+
+#[test]
+fn test_uninit_write_from_slice() {
+    let mut uninit = GenericArray::<i32, U3>::uninit();
+
+    let init = uninit.write_from_slice(&[1, 2, 3]);
+    init[1] = 20;
+
+    assert_eq!(unsafe { uninit.assume_init_ref() }, &arr![1, 20, 3]);
+
+    let counter = Cell::new(0);
+    let src = [Tracked(1, &counter), Tracked(2, &counter)];
+
+    let mut uninit = GenericArray::<Tracked, generic_array::typenum::U2>::uninit();
+    uninit.write_clone_from_slice(&src);
+    assert_eq!(unsafe { uninit.assume_init_mut() }.as_slice(), src);
+
+    unsafe { uninit.assume_init_drop() };
+    assert_eq!(counter.get(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_uninit_write_from_slice_length_panics() {
+    let mut uninit = GenericArray::<i32, U3>::uninit();
+    uninit.write_from_slice(&[1, 2]);
+}
+
+#[test]
+fn test_uninit_write_clone_from_slice_panic() {
+    extern crate std;
+
+    use core::panic::AssertUnwindSafe;
+
+    struct PanicOnClone<'a>(u32, &'a Cell<u32>);
+
+    impl Clone for PanicOnClone<'_> {
+        fn clone(&self) -> Self {
+            assert!(self.0 != 2, "clone panicked");
+            PanicOnClone(self.0, self.1)
+        }
+    }
+
+    impl Drop for PanicOnClone<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    let counter = Cell::new(0);
+    let src: [_; 4] = core::array::from_fn(|i| PanicOnClone(i as u32, &counter));
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut uninit = GenericArray::<PanicOnClone, U4>::uninit();
+        uninit.write_clone_from_slice(&src);
+    }));
+
+    // only the two clones made before the panic are dropped
+    assert!(result.is_err());
+    assert_eq!(counter.get(), 2);
+}
+
 #[cfg(feature = "internals")]
 mod internals {
     use super::Cell;
@@ -0,0 +1,115 @@
+use generic_array::padding::{AnsiX923, Iso7816, Padding, Pkcs7, UnpadError, ZeroPadding};
+use generic_array::typenum::{U0, U256, U4};
+use generic_array::{arr, GenericArray, LengthError};
+
+#[test]
+fn test_pkcs7() {
+    assert_eq!(Pkcs7::pad::<U4>(b"ab").unwrap(), arr![b'a', b'b', 2, 2]);
+    assert_eq!(Pkcs7::pad::<U4>(b"").unwrap(), arr![4, 4, 4, 4]);
+    assert_eq!(Pkcs7::pad::<U4>(b"abc").unwrap(), arr![b'a', b'b', b'c', 1]);
+    assert!(matches!(Pkcs7::pad::<U4>(b"abcd"), Err(LengthError)));
+
+    assert_eq!(Pkcs7::unpad(&arr![b'a', b'b', 2, 2]), Ok(&b"ab"[..]));
+    assert_eq!(Pkcs7::unpad(&arr![4u8, 4, 4, 4]), Ok(&b""[..]));
+
+    assert_eq!(Pkcs7::unpad(&arr![b'a', b'b', 1, 2]), Err(UnpadError));
+    assert_eq!(Pkcs7::unpad(&arr![b'a', b'b', b'c', 0]), Err(UnpadError));
+    assert_eq!(Pkcs7::unpad(&arr![5u8, 5, 5, 5]), Err(UnpadError));
+}
+
+#[test]
+fn test_ansi_x923() {
+    assert_eq!(AnsiX923::pad::<U4>(b"a").unwrap(), arr![b'a', 0, 0, 3]);
+    assert_eq!(AnsiX923::pad::<U4>(b"").unwrap(), arr![0, 0, 0, 4]);
+    assert!(AnsiX923::pad::<U4>(b"abcde").is_err());
+
+    assert_eq!(AnsiX923::unpad(&arr![b'a', 0, 0, 3]), Ok(&b"a"[..]));
+    assert_eq!(AnsiX923::unpad(&arr![b'a', b'b', b'c', 1]), Ok(&b"abc"[..]));
+
+    assert_eq!(AnsiX923::unpad(&arr![b'a', 1, 0, 3]), Err(UnpadError));
+    assert_eq!(AnsiX923::unpad(&arr![0u8, 0, 0, 0]), Err(UnpadError));
+    assert_eq!(AnsiX923::unpad(&arr![0u8, 0, 0, 5]), Err(UnpadError));
+}
+
+#[test]
+fn test_iso7816() {
+    assert_eq!(
+        Iso7816::pad::<U4>(b"ab").unwrap(),
+        arr![b'a', b'b', 0x80, 0]
+    );
+    assert_eq!(Iso7816::pad::<U4>(b"").unwrap(), arr![0x80, 0, 0, 0]);
+    assert!(Iso7816::pad::<U4>(b"abcd").is_err());
+
+    assert_eq!(Iso7816::unpad(&arr![b'a', b'b', 0x80, 0]), Ok(&b"ab"[..]));
+    assert_eq!(Iso7816::unpad(&arr![0x80u8, 0x80, 0, 0]), Ok(&b"\x80"[..]));
+
+    assert_eq!(Iso7816::unpad(&arr![b'a', b'b', 0, 0]), Err(UnpadError));
+    assert_eq!(Iso7816::unpad(&arr![0u8, 0, 0, 0]), Err(UnpadError));
+}
+
+#[test]
+fn test_zero_padding() {
+    assert_eq!(
+        ZeroPadding::pad::<U4>(b"ab").unwrap(),
+        arr![b'a', b'b', 0, 0]
+    );
+    assert!(ZeroPadding::pad::<U4>(b"abcd").is_err());
+
+    assert_eq!(ZeroPadding::unpad(&arr![b'a', b'b', 0, 0]), Ok(&b"ab"[..]));
+    assert_eq!(
+        ZeroPadding::unpad(&arr![b'a', b'b', b'c', b'd']),
+        Ok(&b"abcd"[..])
+    );
+    assert_eq!(ZeroPadding::unpad(&arr![0u8, 0, 0, 0]), Ok(&b""[..]));
+}
+
+#[test]
+fn test_zero_length_block() {
+    assert!(Pkcs7::pad::<U0>(b"").is_err());
+    assert!(Iso7816::pad::<U0>(b"").is_err());
+
+    let empty = GenericArray::<u8, U0>::default();
+    assert_eq!(Pkcs7::unpad(&empty), Err(UnpadError));
+    assert_eq!(AnsiX923::unpad(&empty), Err(UnpadError));
+    assert_eq!(Iso7816::unpad(&empty), Err(UnpadError));
+    assert_eq!(ZeroPadding::unpad(&empty), Ok(&b""[..]));
+}
+
+#[test]
+#[should_panic]
+fn test_pkcs7_block_too_large() {
+    let _ = Pkcs7::pad::<U256>(b"");
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn test_unpad_ct_matches_unpad() {
+    fn check<P: Padding>(block: &GenericArray<u8, generic_array::typenum::U8>) {
+        let ct: Option<&[u8]> = P::unpad_ct(block).into();
+        assert_eq!(ct, P::unpad(block).ok(), "{:?}", block);
+    }
+
+    // every combination of the last three bytes over a set of interesting values,
+    // covering valid and invalid padding of each scheme
+    let values = [0u8, 1, 2, 3, 7, 8, 9, 0x80, 0xFF];
+
+    for &a in &values {
+        for &b in &values {
+            for &c in &values {
+                let block = arr![b'x', b'y', 3, 3, 0, a, b, c];
+
+                check::<Pkcs7>(&block);
+                check::<AnsiX923>(&block);
+                check::<Iso7816>(&block);
+                check::<ZeroPadding>(&block);
+            }
+        }
+    }
+
+    let full = arr![8u8; 8];
+    check::<Pkcs7>(&full);
+
+    let empty = GenericArray::<u8, U0>::default();
+    assert!(bool::from(Pkcs7::unpad_ct(&empty).is_none()));
+    assert!(bool::from(ZeroPadding::unpad_ct(&empty).is_some()));
+}
@@ -0,0 +1,147 @@
+use core::fmt::Write;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use generic_array::string::GenericString;
+use generic_array::typenum::{U0, U4, U8};
+use generic_array::{arr, CapacityError, GenericArray};
+
+#[test]
+fn test_push() {
+    let mut s = GenericString::<U8>::new();
+    assert!(s.is_empty());
+    assert_eq!(GenericString::<U8>::capacity(), 8);
+
+    s.push_str("abc").unwrap();
+    s.push('é').unwrap();
+    assert_eq!(s, "abcé");
+    assert_eq!(s.len(), 5);
+    assert_eq!(s.remaining_capacity(), 3);
+
+    // all or nothing
+    assert_eq!(s.push_str("defg"), Err(CapacityError));
+    assert_eq!(s, "abcé");
+
+    s.push_str("def").unwrap();
+    assert!(s.is_full());
+    assert_eq!(s.push('x'), Err(CapacityError));
+    assert_eq!(s.push_str(""), Ok(()));
+
+    let mut z = GenericString::<U0>::new();
+    assert!(z.is_full());
+    assert_eq!(z.push('x'), Err(CapacityError));
+    assert_eq!(z.as_str(), "");
+}
+
+#[test]
+fn test_pop_truncate() {
+    let mut s: GenericString<U8> = "aé€".parse().unwrap();
+    assert_eq!(s.pop(), Some('€'));
+    assert_eq!(s.pop(), Some('é'));
+    assert_eq!(s.as_array(), &arr![b'a', 0, 0, 0, 0, 0, 0, 0]);
+
+    s.push_str("bcd").unwrap();
+    s.truncate(10);
+    assert_eq!(s, "abcd");
+    s.truncate(2);
+    assert_eq!(s, "ab");
+    s.clear();
+    assert_eq!(s.pop(), None);
+    assert_eq!(s.as_array(), &GenericArray::default());
+}
+
+#[test]
+#[should_panic]
+fn test_truncate_not_char_boundary() {
+    let mut s: GenericString<U4> = "é".parse().unwrap();
+    s.truncate(1);
+}
+
+#[test]
+fn test_fmt_write() {
+    let mut s = GenericString::<U8>::new();
+    write!(s, "{}-{}", 12, 34).unwrap();
+    assert_eq!(s, "12-34");
+
+    // fails once the capacity is exceeded, keeping what was written before
+    assert!(write!(s, "{}", 5678).is_err());
+    assert_eq!(s, "12-34");
+
+    assert_eq!(format!("{}", s), "12-34");
+    assert_eq!(format!("{:?}", s), "\"12-34\"");
+    assert_eq!(format!("{:>7}", s), "  12-34");
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("abcd".parse::<GenericString<U4>>().unwrap(), "abcd");
+    assert_eq!("abcde".parse::<GenericString<U4>>(), Err(CapacityError));
+    assert!(GenericString::<U4>::try_from("abc").is_ok());
+    assert!(GenericString::<U4>::try_from("abcde").is_err());
+}
+
+#[test]
+fn test_parts() {
+    let s: GenericString<U4> = "hé".parse().unwrap();
+    let (buf, len) = s.into_parts();
+    assert_eq!(buf, arr![b'h', 0xC3, 0xA9, 0]);
+    assert_eq!(len, 3);
+
+    let s = GenericString::from_parts(arr![b'a', b'b', b'c', b'd'], 2).unwrap();
+    assert_eq!(s, "ab");
+    // bytes beyond the length are cleared
+    assert_eq!(s.as_array(), &arr![b'a', b'b', 0, 0]);
+
+    // cut in the middle of 'é'
+    assert!(GenericString::<U4>::from_parts(buf, 2).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_from_parts_too_long() {
+    let _ = GenericString::<U4>::from_parts(GenericArray::default(), 5);
+}
+
+#[test]
+fn test_deref() {
+    let mut s: GenericString<U8> = "hello".parse().unwrap();
+    assert!(s.starts_with("he"));
+    assert_eq!(s.find('l'), Some(2));
+
+    s.make_ascii_uppercase();
+    assert_eq!(s, "HELLO");
+    assert_eq!(AsRef::<[u8]>::as_ref(&s), b"HELLO");
+}
+
+#[test]
+fn test_cmp_hash() {
+    fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut h = DefaultHasher::new();
+        value.hash(&mut h);
+        h.finish()
+    }
+
+    let a: GenericString<U4> = "abc".parse().unwrap();
+    let b: GenericString<U8> = "abc".parse().unwrap();
+    let c: GenericString<U4> = "abd".parse().unwrap();
+
+    assert_eq!(a, b);
+    assert_eq!("abc", a);
+    assert!(a < c);
+    assert!(b < c);
+    assert_eq!(a.cmp(&c), "abc".cmp("abd"));
+
+    // hashes like `str`, so it can be looked up through `Borrow<str>`
+    assert_eq!(hash_of(&a), hash_of("abc"));
+    let set: HashSet<GenericString<U4>> = [a, c].into_iter().collect();
+    assert!(set.contains("abd"));
+    assert!(!set.contains("abe"));
+}
+
+#[rustversion::since(1.75)]
+#[test]
+fn test_const() {
+    const EMPTY: GenericString<U4> = GenericString::new();
+    assert!(EMPTY.is_empty());
+}